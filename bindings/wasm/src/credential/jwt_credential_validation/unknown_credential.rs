@@ -27,6 +27,17 @@ impl WasmUnknownCredentialContainer {
     WasmUnknownCredentialContainer(inner)
   }
 
+  /// Returns the kind of the contained value: `"jwt"`, `"credential"` or `"other"`.
+  #[wasm_bindgen]
+  pub fn kind(&self) -> String {
+    match &self.0 {
+      UnknownCredential::Jwt(_) => "jwt",
+      UnknownCredential::Credential(_) => "credential",
+      UnknownCredential::Other(_) => "other",
+    }
+    .to_owned()
+  }
+
   /// Returns a {@link Jwt} if the credential is of type string, `undefined` otherwise.
   #[wasm_bindgen(js_name = tryIntoJwt)]
   pub fn try_into_jwt(&self) -> Option<WasmJwt> {
@@ -69,4 +69,10 @@ interface IJwtPresentationValidationOptions {
      * Uses the current datetime during validation if not set. 
      */
     readonly latestIssuanceDate?: Timestamp;
+
+    /**
+     * Declare that the presentation is **not** considered valid unless its `aud` claim matches this URL,
+     * e.g. the domain of the verifier. The audience is not checked if not set.
+     */
+    readonly audience?: string;
 }"#;
@@ -278,6 +278,11 @@ describe("Presentation", function() {
                 unsignedVc.toJSON(),
             );
             assert.deepStrictEqual(credentials[2].tryIntoRaw()!, otherCredential);
+
+            assert.deepStrictEqual(credentials.map((credential) => credential.kind()), ["jwt", "credential", "other"]);
+            assert.deepStrictEqual(credentials[0].tryIntoRaw(), undefined);
+            assert.deepStrictEqual(credentials[1].tryIntoJwt(), undefined);
+            assert.deepStrictEqual(credentials[2].tryIntoCredential(), undefined);
         });
     });
 });
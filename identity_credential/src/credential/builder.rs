@@ -169,6 +169,12 @@ impl<T> CredentialBuilder<T> {
   }
 
   /// Returns a new `Credential` based on the `CredentialBuilder` configuration.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::MissingIssuer`](crate::Error::MissingIssuer) if no issuer is set,
+  /// [`Error::InvalidExpirationDate`](crate::Error::InvalidExpirationDate) if the expiration date is before the
+  /// issuance date, or any error of [`Credential::check_structure`].
   pub fn build(self) -> Result<Credential<T>> {
     Credential::from_builder(self)
   }
@@ -223,6 +229,8 @@ mod tests {
   use crate::credential::CredentialBuilder;
   use crate::credential::Proof;
   use crate::credential::Subject;
+  use crate::error::Error;
+  use crate::error::Result;
 
   fn subject() -> Subject {
     let json: Value = json!({
@@ -293,4 +301,29 @@ mod tests {
   fn test_builder_missing_issuer() {
     let _: Credential = CredentialBuilder::default().subject(subject()).build().unwrap();
   }
+
+  #[test]
+  fn test_builder_expiration_date() {
+    let issuance_date: Timestamp = Timestamp::parse("2010-01-01T00:00:00Z").unwrap();
+    let builder = || {
+      CredentialBuilder::default()
+        .subject(subject())
+        .issuer(issuer())
+        .issuance_date(issuance_date)
+    };
+
+    let credential: Credential = builder()
+      .expiration_date(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+      .build()
+      .unwrap();
+    assert_eq!(credential.expiration_date.unwrap().to_string(), "2020-01-01T00:00:00Z");
+
+    let result: Result<Credential> = builder()
+      .expiration_date(Timestamp::parse("2009-12-31T23:59:59Z").unwrap())
+      .build();
+    assert!(matches!(result.unwrap_err(), Error::InvalidExpirationDate));
+
+    let credential: Credential = builder().expiration_date(issuance_date).build().unwrap();
+    assert_eq!(credential.expiration_date, Some(issuance_date));
+  }
 }
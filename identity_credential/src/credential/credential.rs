@@ -125,6 +125,11 @@ impl<T> Credential<T> {
 
     this.check_structure()?;
 
+    // The credential must not expire before it is issued.
+    if matches!(this.expiration_date, Some(expiration_date) if expiration_date < this.issuance_date) {
+      return Err(Error::InvalidExpirationDate);
+    }
+
     Ok(this)
   }
 
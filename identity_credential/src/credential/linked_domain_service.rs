@@ -158,6 +158,7 @@ mod tests {
   use identity_core::common::OrderedSet;
   use identity_core::common::Url;
   use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
   use identity_did::DIDUrl;
   use identity_document::service::Service;
   use serde_json::json;
@@ -184,6 +185,21 @@ mod tests {
     assert_eq!(Service::from(service), service_from_json);
   }
 
+  #[test]
+  fn test_service_multiple_origins_round_trip() {
+    let json: &str = r#"{"id":"did:example:123#foo","type":"LinkedDomains","serviceEndpoint":{"origins":["https://foo.example.com/","https://bar.example.com/"]}}"#;
+    let service: Service = Service::from_json(json).unwrap();
+    let service: LinkedDomainService = LinkedDomainService::try_from(service).unwrap();
+    assert_eq!(
+      service.domains(),
+      &[
+        Url::parse("https://foo.example.com").unwrap(),
+        Url::parse("https://bar.example.com").unwrap()
+      ]
+    );
+    assert_eq!(Service::from(service).to_json().unwrap(), json);
+  }
+
   #[test]
   fn test_create_service_single_origin() {
     let mut domains: OrderedSet<Url> = OrderedSet::new();
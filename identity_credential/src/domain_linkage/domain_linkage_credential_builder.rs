@@ -69,6 +69,11 @@ impl DomainLinkageCredentialBuilder {
   }
 
   /// Returns a new `Credential` based on the `DomainLinkageCredentialBuilder` configuration.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the `issuer`, `expirationDate` or `origin` is missing, if the origin is not a domain origin,
+  /// or [`Error::InvalidExpirationDate`] if the expiration date is before the issuance date.
   pub fn build(self) -> Result<Credential<Object>> {
     let origin: Url = self.origin.ok_or(Error::MissingOrigin)?;
     if origin.domain().is_none() {
@@ -85,6 +90,12 @@ impl DomainLinkageCredentialBuilder {
     let mut properties: Object = Object::new();
     properties.insert("origin".into(), origin.into_string().into());
     let issuer: Url = self.issuer.ok_or(Error::MissingIssuer)?;
+    let issuance_date: Timestamp = self.issuance_date.unwrap_or_else(Timestamp::now_utc);
+    let expiration_date: Timestamp = self.expiration_date.ok_or(Error::MissingExpirationDate)?;
+    // The credential must not expire before it is issued.
+    if expiration_date < issuance_date {
+      return Err(Error::InvalidExpirationDate);
+    }
 
     Ok(Credential {
       context: OneOrMany::Many(vec![
@@ -98,8 +109,8 @@ impl DomainLinkageCredentialBuilder {
       ]),
       credential_subject: OneOrMany::One(Subject::with_id_and_properties(issuer.clone(), properties)),
       issuer: Issuer::Url(issuer),
-      issuance_date: self.issuance_date.unwrap_or_else(Timestamp::now_utc),
-      expiration_date: Some(self.expiration_date.ok_or(Error::MissingExpirationDate)?),
+      issuance_date,
+      expiration_date: Some(expiration_date),
       credential_status: None,
       credential_schema: Vec::new().into(),
       refresh_service: Vec::new().into(),
@@ -201,4 +212,17 @@ mod tests {
 
     assert!(matches!(credential, Err(Error::MissingExpirationDate)));
   }
+
+  #[test]
+  fn test_builder_expiration_date_before_issuance_date() {
+    let issuer: CoreDID = "did:example:issuer".parse().unwrap();
+    let credential: Result<Credential> = DomainLinkageCredentialBuilder::new()
+      .issuance_date(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+      .expiration_date(Timestamp::parse("2019-12-31T23:59:59Z").unwrap())
+      .issuer(issuer)
+      .origin(Url::parse("http://www.example.com").unwrap())
+      .build();
+
+    assert!(matches!(credential, Err(Error::InvalidExpirationDate)));
+  }
 }
@@ -28,6 +28,9 @@ pub enum Error {
   /// Caused when constructing a Domain Linkage credential without an origin.
   #[error("missing origin")]
   MissingOrigin,
+  /// Caused when constructing a credential whose expiration date is before its issuance date.
+  #[error("expiration date must not be before the issuance date")]
+  InvalidExpirationDate,
   /// Caused when constructing a credential with a malformed subject.
   #[error("invalid credential subject")]
   InvalidSubject,
@@ -46,6 +46,9 @@ pub enum JwtValidationError {
   /// Indicates that the issuance date of the credential or presentation is not considered valid.
   #[error("issuance date is in the future or later than required")]
   IssuanceDate,
+  /// Indicates that the audience of the presentation is missing or does not match the expected one.
+  #[error("the presentation's audience is missing or does not match the expected audience")]
+  AudienceMismatch,
   /// Indicates that the credential's (resp. presentation's) signature could not be verified using
   /// the issuer's (resp. holder's) DID Document.
   #[error("could not verify the {signer_ctx}'s signature")]
@@ -94,36 +94,52 @@ impl JwtCredentialValidatorUtils {
     status_check: crate::validator::StatusCheck,
   ) -> ValidationUnitResult {
     use crate::revocation::status_list_2021::CredentialStatus;
-    use crate::revocation::status_list_2021::StatusList2021Entry;
 
     if status_check == crate::validator::StatusCheck::SkipAll {
       return Ok(());
     }
 
-    match &credential.credential_status {
-      None => Ok(()),
-      Some(status) => {
-        let status = StatusList2021Entry::try_from(status)
-          .map_err(|e| JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(e.to_string())))?;
-        if Some(status.status_list_credential()) == status_list_credential.id.as_ref()
-          && status.purpose() == status_list_credential.purpose()
-        {
-          let entry_status = status_list_credential
-            .entry(status.index())
-            .map_err(|e| JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(e.to_string())))?;
-          match entry_status {
-            CredentialStatus::Revoked => Err(JwtValidationError::Revoked),
-            CredentialStatus::Suspended => Err(JwtValidationError::Suspended),
-            CredentialStatus::Valid => Ok(()),
-          }
-        } else {
-          Err(JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(
-            "The given statusListCredential doesn't match the credential's status".to_owned(),
-          )))
-        }
-      }
+    match Self::status_with_status_list_2021(credential, status_list_credential)? {
+      Some(CredentialStatus::Revoked) => Err(JwtValidationError::Revoked),
+      Some(CredentialStatus::Suspended) => Err(JwtValidationError::Suspended),
+      Some(CredentialStatus::Valid) | None => Ok(()),
+    }
+  }
+
+  /// Returns the status of the [`Credential`] as recorded in the given [`StatusList2021Credential`].
+  ///
+  /// Returns `Ok(None)` if the credential does not have a `credentialStatus`, in which case its status is unknown.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `credentialStatus` is not a valid `StatusList2021Entry` or does not reference
+  /// `status_list_credential`.
+  #[cfg(feature = "status-list-2021")]
+  pub fn status_with_status_list_2021<T>(
+    credential: &Credential<T>,
+    status_list_credential: &StatusList2021Credential,
+  ) -> ValidationUnitResult<Option<crate::revocation::status_list_2021::CredentialStatus>> {
+    use crate::revocation::status_list_2021::StatusList2021Entry;
+
+    let Some(status) = &credential.credential_status else {
+      return Ok(None);
+    };
+    let status = StatusList2021Entry::try_from(status)
+      .map_err(|e| JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(e.to_string())))?;
+    if Some(status.status_list_credential()) != status_list_credential.id.as_ref()
+      || status.purpose() != status_list_credential.purpose()
+    {
+      return Err(JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(
+        "The given statusListCredential doesn't match the credential's status".to_owned(),
+      )));
     }
+
+    status_list_credential
+      .entry(status.index())
+      .map(Some)
+      .map_err(|e| JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(e.to_string())))
   }
+
   /// Checks whether the credential status has been revoked.
   ///
   /// Only supports `RevocationBitmap2022`.
@@ -233,3 +249,78 @@ impl JwtCredentialValidatorUtils {
     })
   }
 }
+
+#[cfg(all(test, feature = "status-list-2021"))]
+mod tests {
+  use identity_core::common::Url;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Issuer;
+  use crate::credential::Subject;
+  use crate::revocation::status_list_2021::CredentialStatus;
+  use crate::revocation::status_list_2021::StatusList2021;
+  use crate::revocation::status_list_2021::StatusList2021CredentialBuilder;
+  use crate::revocation::status_list_2021::StatusPurpose;
+  use crate::validator::StatusCheck;
+
+  fn credential() -> Credential {
+    CredentialBuilder::default()
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap()
+  }
+
+  fn new_status_list_credential(purpose: StatusPurpose) -> StatusList2021Credential {
+    let url = Url::parse("https://example.com/status/1").unwrap();
+    StatusList2021CredentialBuilder::new(StatusList2021::default())
+      .issuer(Issuer::Url(Url::parse("did:example:issuer").unwrap()))
+      .purpose(purpose)
+      .subject_id(url)
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_status_with_status_list_2021() {
+    let mut status_list_credential = new_status_list_credential(StatusPurpose::Revocation);
+
+    // A credential without `credentialStatus` has an unknown status.
+    let mut credential = credential();
+    assert_eq!(
+      JwtCredentialValidatorUtils::status_with_status_list_2021(&credential, &status_list_credential).unwrap(),
+      None
+    );
+
+    status_list_credential
+      .set_credential_status(&mut credential, 42, false)
+      .unwrap();
+    assert_eq!(
+      JwtCredentialValidatorUtils::status_with_status_list_2021(&credential, &status_list_credential).unwrap(),
+      Some(CredentialStatus::Valid)
+    );
+
+    status_list_credential.update(|list| list.set_entry(42, true)).unwrap();
+    assert_eq!(
+      JwtCredentialValidatorUtils::status_with_status_list_2021(&credential, &status_list_credential).unwrap(),
+      Some(CredentialStatus::Revoked)
+    );
+    assert!(matches!(
+      JwtCredentialValidatorUtils::check_status_with_status_list_2021(
+        &credential,
+        &status_list_credential,
+        StatusCheck::Strict
+      )
+      .unwrap_err(),
+      JwtValidationError::Revoked
+    ));
+
+    // A status list with a different purpose does not match the credential's status.
+    let suspension_list_credential = new_status_list_credential(StatusPurpose::Suspension);
+    assert!(matches!(
+      JwtCredentialValidatorUtils::status_with_status_list_2021(&credential, &suspension_list_credential).unwrap_err(),
+      JwtValidationError::InvalidStatus(_)
+    ));
+  }
+}
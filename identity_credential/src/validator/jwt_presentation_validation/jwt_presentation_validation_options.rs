@@ -5,6 +5,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use identity_core::common::Timestamp;
+use identity_core::common::Url;
 use identity_document::verifiable::JwsVerificationOptions;
 
 /// Criteria for validating a [`Presentation`](crate::presentation::Presentation).
@@ -27,6 +28,11 @@ pub struct JwtPresentationValidationOptions {
   /// Uses the current datetime during validation if not set.
   #[serde(default)]
   pub latest_issuance_date: Option<Timestamp>,
+
+  /// Declares that the presentation is **not** considered valid unless its `aud` claim matches this [`Url`],
+  /// e.g. the domain of the verifier. The audience is not checked if not set.
+  #[serde(default)]
+  pub audience: Option<Url>,
 }
 
 impl JwtPresentationValidationOptions {
@@ -54,4 +60,10 @@ impl JwtPresentationValidationOptions {
     self.latest_issuance_date = Some(timestamp);
     self
   }
+
+  /// Declare that the presentation is **not** considered valid unless its `aud` claim matches this [`Url`].
+  pub fn audience(mut self, audience: Url) -> Self {
+    self.audience = Some(audience);
+    self
+  }
 }
@@ -40,6 +40,7 @@ where
   /// The following properties are validated according to `options`:
   /// - the JWT can be decoded into a semantically valid presentation.
   /// - the expiration and issuance date contained in the JWT claims.
+  /// - the audience contained in the JWT claims, if an expected audience is set.
   /// - the holder's signature.
   ///
   /// Validation is done with respect to the properties set in `options`.
@@ -145,7 +146,14 @@ where
         JwtValidationError::IssuanceDate,
       ))?;
 
+    // Check the audience.
     let aud: Option<Url> = claims.aud.clone();
+    if options.audience.is_some() && aud != options.audience {
+      return Err(CompoundJwtPresentationValidationError::one_presentation_error(
+        JwtValidationError::AudienceMismatch,
+      ));
+    }
+
     let custom_claims: Option<Object> = claims.custom.clone();
 
     let presentation: Presentation<CRED, T> = claims.try_into_presentation().map_err(|err| {
@@ -111,6 +111,19 @@ impl CoreDID {
   ///
   /// Returns `Err` if the input is not a valid [`DID`].
   pub fn parse(input: impl AsRef<str>) -> Result<Self, Error> {
+    let input: &str = input.as_ref();
+
+    // The base parser skips the character after a percent-encoded octet in the method-id and may read past the end
+    // of the input, so such a method-id is validated here and set after the remainder has been parsed.
+    if let Some((prefix, method_id, suffix)) =
+      split_method_id(input).filter(|(_, method_id, _)| method_id.contains('%'))
+    {
+      Self::valid_method_id(method_id)?;
+      let mut did: BaseDIDUrl = BaseDIDUrl::parse(format!("{prefix}0{suffix}"))?;
+      did.set_method_id(method_id);
+      return Ok(Self(did));
+    }
+
     BaseDIDUrl::parse(input).map(Self).map_err(Error::from)
   }
 
@@ -138,21 +151,8 @@ impl CoreDID {
 
   /// Validates whether a string is a valid [`DID`] method-id.
   pub fn valid_method_id(value: &str) -> Result<(), Error> {
-    // if !value.chars().all(is_char_method_id) {
-    //   return Err(Error::InvalidMethodId);
-    // }
-    let mut chars = value.chars();
-    while let Some(c) = chars.next() {
-      match c {
-        '%' => {
-          let digits = chars.clone().take(2).collect::<String>();
-          u8::from_str_radix(&digits, 16).map_err(|_| Error::InvalidMethodId)?;
-          chars.next();
-          chars.next();
-        }
-        c if is_char_method_id(c) => (),
-        _ => return Err(Error::InvalidMethodId),
-      }
+    if !is_valid_component(value, is_char_method_id) {
+      return Err(Error::InvalidMethodId);
     }
 
     Ok(())
@@ -279,6 +279,33 @@ pub(crate) const fn is_char_method_id(ch: char) -> bool {
   matches!(ch, '0'..='9' | 'a'..='z' | 'A'..='Z' | '.' | '-' | '_' | ':')
 }
 
+/// Splits a DID or DID Url into the part preceding the method-id, the method-id and the part following it.
+fn split_method_id(input: &str) -> Option<(&str, &str, &str)> {
+  let start: usize = input.match_indices(':').nth(1)?.0 + 1;
+  let end: usize = input[start..]
+    .find(['/', '?', '#'])
+    .map_or(input.len(), |index| start + index);
+  Some((&input[..start], &input[start..end], &input[end..]))
+}
+
+/// Checks whether every character of a DID or DID Url component satisfies `is_char` or is part of a percent-encoded octet,
+/// i.e. a '%' followed by two hexadecimal digits.
+pub(crate) fn is_valid_component(component: &str, is_char: fn(char) -> bool) -> bool {
+  let mut chars = component.chars();
+  while let Some(ch) = chars.next() {
+    let valid: bool = if ch == '%' {
+      matches!(chars.next(), Some(hex) if hex.is_ascii_hexdigit())
+        && matches!(chars.next(), Some(hex) if hex.is_ascii_hexdigit())
+    } else {
+      is_char(ch)
+    };
+    if !valid {
+      return false;
+    }
+  }
+  true
+}
+
 impl<D> DID for D where
   D: Clone
     + PartialEq
@@ -310,6 +337,24 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_core_did_percent_encoding() {
+    for input in ["did:example:a%00", "did:example:a%2Fb", "did:example:%41%42"] {
+      let did: CoreDID = CoreDID::parse(input).unwrap();
+      assert_eq!(did.as_str(), input);
+      assert_eq!(did.method(), "example");
+      assert_eq!(did.method_id(), &input["did:example:".len()..]);
+    }
+    for input in [
+      "did:example:a%0",
+      "did:example:a%0x",
+      "did:example:a%+1",
+      "did:example:a%00!",
+    ] {
+      assert!(matches!(CoreDID::parse(input), Err(Error::InvalidMethodId)));
+    }
+  }
+
   #[test]
   fn test_core_did_invalid() {
     assert!(CoreDID::parse("").is_err());
@@ -0,0 +1,282 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+
+use identity_core::convert::BaseEncoding;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::CoreDID;
+use crate::Error;
+use crate::DID;
+
+/// The types of public keys that can be encoded in a [`DIDKey`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DIDKeyType {
+  /// An Ed25519 public key (multicodec `ed25519-pub`).
+  Ed25519,
+  /// An X25519 public key (multicodec `x25519-pub`).
+  X25519,
+}
+
+impl DIDKeyType {
+  /// The length of a public key of this type in bytes.
+  pub const fn public_key_len(self) -> usize {
+    match self {
+      Self::Ed25519 | Self::X25519 => 32,
+    }
+  }
+
+  /// The type of the verification method expressing a public key of this type in a `did:key` DID Document.
+  pub const fn method_type(self) -> &'static str {
+    match self {
+      Self::Ed25519 => "Ed25519VerificationKey2020",
+      Self::X25519 => "X25519KeyAgreementKey2020",
+    }
+  }
+
+  /// Whether a public key of this type is used for key agreement rather than for signing.
+  pub const fn is_key_agreement(self) -> bool {
+    match self {
+      Self::Ed25519 => false,
+      Self::X25519 => true,
+    }
+  }
+
+  /// The varint-encoded multicodec prefix of a public key of this type.
+  const fn multicodec(self) -> [u8; 2] {
+    match self {
+      Self::Ed25519 => [0xed, 0x01],
+      Self::X25519 => [0xec, 0x01],
+    }
+  }
+
+  fn from_multicodec(prefix: &[u8]) -> Option<Self> {
+    [Self::Ed25519, Self::X25519]
+      .into_iter()
+      .find(|key_type| key_type.multicodec() == prefix)
+  }
+}
+
+/// A DID conforming to the [`did:key` method](https://w3c-ccg.github.io/did-method-key/).
+///
+/// The method-specific identifier is the base58-btc multibase encoding of a multicodec-prefixed public key, so the
+/// DID Document can be derived from the DID itself without any network access.
+#[derive(Clone, Hash, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[repr(transparent)]
+#[serde(into = "CoreDID", try_from = "CoreDID")]
+pub struct DIDKey(CoreDID);
+
+impl DIDKey {
+  /// The `did:key` method name.
+  pub const METHOD: &'static str = "key";
+
+  /// Constructs a [`DIDKey`] from the given public key.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `public_key` does not have the length expected for `key_type`.
+  pub fn new(key_type: DIDKeyType, public_key: &[u8]) -> Result<Self, Error> {
+    if public_key.len() != key_type.public_key_len() {
+      return Err(Error::Other("invalid public key length"));
+    }
+    let key: Vec<u8> = [key_type.multicodec().as_slice(), public_key].concat();
+    let did: String = format!("did:{}:{}", Self::METHOD, BaseEncoding::encode_multibase(&key, None));
+
+    Self::parse(did)
+  }
+
+  /// Parses a [`DIDKey`] from the given `input`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input is not a valid `did:key` DID or encodes an unsupported key type.
+  pub fn parse(input: impl AsRef<str>) -> Result<Self, Error> {
+    CoreDID::parse(input).and_then(Self::try_from_core)
+  }
+
+  /// Converts a [`CoreDID`] to a [`DIDKey`].
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input is not a valid `did:key` DID or encodes an unsupported key type.
+  pub fn try_from_core(did: CoreDID) -> Result<Self, Error> {
+    if did.method() != Self::METHOD {
+      return Err(Error::InvalidMethodName);
+    }
+    Self::decode(did.method_id())?;
+
+    Ok(Self(did))
+  }
+
+  /// Returns the type of the encoded public key.
+  pub fn key_type(&self) -> DIDKeyType {
+    self.decoded().0
+  }
+
+  /// Returns the encoded public key.
+  pub fn public_key(&self) -> Vec<u8> {
+    self.decoded().1
+  }
+
+  /// Returns the multibase encoding of the multicodec-prefixed public key, i.e. the method-specific identifier.
+  pub fn multibase(&self) -> &str {
+    self.method_id()
+  }
+
+  fn decoded(&self) -> (DIDKeyType, Vec<u8>) {
+    Self::decode(self.method_id()).expect("the method id is checked during DID creation")
+  }
+
+  fn decode(method_id: &str) -> Result<(DIDKeyType, Vec<u8>), Error> {
+    // Only base58-btc is allowed by the method specification.
+    if !method_id.starts_with('z') {
+      return Err(Error::InvalidMethodId);
+    }
+    let mut key: Vec<u8> = BaseEncoding::decode_multibase(method_id).map_err(|_| Error::InvalidMethodId)?;
+    if key.len() < 2 {
+      return Err(Error::InvalidMethodId);
+    }
+    let key_type: DIDKeyType =
+      DIDKeyType::from_multicodec(&key[..2]).ok_or(Error::Other("unsupported multicodec key type"))?;
+    let public_key: Vec<u8> = key.split_off(2);
+    if public_key.len() != key_type.public_key_len() {
+      return Err(Error::InvalidMethodId);
+    }
+
+    Ok((key_type, public_key))
+  }
+}
+
+impl FromStr for DIDKey {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::parse(string)
+  }
+}
+
+impl TryFrom<&str> for DIDKey {
+  type Error = Error;
+
+  fn try_from(other: &str) -> Result<Self, Self::Error> {
+    Self::parse(other)
+  }
+}
+
+impl TryFrom<String> for DIDKey {
+  type Error = Error;
+
+  fn try_from(other: String) -> Result<Self, Self::Error> {
+    Self::parse(other)
+  }
+}
+
+impl TryFrom<CoreDID> for DIDKey {
+  type Error = Error;
+
+  fn try_from(value: CoreDID) -> Result<Self, Self::Error> {
+    Self::try_from_core(value)
+  }
+}
+
+impl From<DIDKey> for CoreDID {
+  fn from(did: DIDKey) -> Self {
+    did.0
+  }
+}
+
+impl From<DIDKey> for String {
+  fn from(did: DIDKey) -> Self {
+    did.0.into()
+  }
+}
+
+impl AsRef<CoreDID> for DIDKey {
+  fn as_ref(&self) -> &CoreDID {
+    &self.0
+  }
+}
+
+impl Display for DIDKey {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Test vectors from the did:key method specification.
+  const ED25519_DID: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+  const ED25519_KEY: &str = "2e6fcce36701dc791488e0d0b1745cc1e33a4c1c9fcc41c63bd343dbbe0970e6";
+  const X25519_DID: &str = "did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F";
+  const X25519_KEY: &str = "2fe57da347cd62431528daac5fbb290730fff684afc4cfc2ed90995f58cb3b74";
+  const SECP256K1_DID: &str = "did:key:zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme";
+
+  fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+      .step_by(2)
+      .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).unwrap())
+      .collect()
+  }
+
+  #[test]
+  fn test_encode_decode() {
+    for (did, key, key_type) in [
+      (ED25519_DID, ED25519_KEY, DIDKeyType::Ed25519),
+      (X25519_DID, X25519_KEY, DIDKeyType::X25519),
+    ] {
+      let public_key: Vec<u8> = decode_hex(key);
+      let encoded: DIDKey = DIDKey::new(key_type, &public_key).unwrap();
+      assert_eq!(encoded.as_str(), did);
+
+      let parsed: DIDKey = DIDKey::parse(did).unwrap();
+      assert_eq!(parsed, encoded);
+      assert_eq!(parsed.key_type(), key_type);
+      assert_eq!(parsed.public_key(), public_key);
+      assert_eq!(parsed.multibase(), &did["did:key:".len()..]);
+    }
+  }
+
+  #[test]
+  fn test_invalid() {
+    assert!(matches!(
+      DIDKey::parse(SECP256K1_DID),
+      Err(Error::Other("unsupported multicodec key type"))
+    ));
+    assert!(matches!(
+      DIDKey::parse("did:example:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"),
+      Err(Error::InvalidMethodName)
+    ));
+    // Not base58-btc.
+    assert!(matches!(
+      DIDKey::parse("did:key:f6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"),
+      Err(Error::InvalidMethodId)
+    ));
+    // Truncated key.
+    let truncated: String = BaseEncoding::encode_multibase(&[[0xed, 0x01].as_slice(), &[0; 31]].concat(), None);
+    assert!(matches!(
+      DIDKey::parse(format!("did:key:{truncated}")),
+      Err(Error::InvalidMethodId)
+    ));
+    assert!(matches!(
+      DIDKey::new(DIDKeyType::Ed25519, &[0; 31]),
+      Err(Error::Other("invalid public key length"))
+    ));
+  }
+
+  #[test]
+  fn test_serde() {
+    let did: DIDKey = DIDKey::parse(ED25519_DID).unwrap();
+    let json: String = serde_json::to_string(&did).unwrap();
+    assert_eq!(json, format!("\"{ED25519_DID}\""));
+    assert_eq!(serde_json::from_str::<DIDKey>(&json).unwrap(), did);
+    assert!(serde_json::from_str::<DIDKey>(&format!("\"{SECP256K1_DID}\"")).is_err());
+  }
+}
@@ -10,12 +10,11 @@ use std::cmp::Ordering;
 use std::hash::Hash;
 use std::hash::Hasher;
 
-use did_url_parser::DID as BaseDIDUrl;
-
 use identity_core::common::KeyComparable;
 use identity_core::common::Url;
 
 use crate::did::is_char_method_id;
+use crate::did::is_valid_component;
 use crate::did::CoreDID;
 use crate::did::DID;
 use crate::Error;
@@ -96,7 +95,7 @@ impl RelativeDIDUrl {
     self.path = value
       .filter(|s| !s.is_empty())
       .map(|s| {
-        if s.starts_with('/') && s.chars().all(is_char_path) {
+        if s.starts_with('/') && is_valid_component(s, is_char_path) {
           Ok(s.to_owned())
         } else {
           Err(Error::InvalidPath)
@@ -138,7 +137,7 @@ impl RelativeDIDUrl {
       .map(|mut s| {
         // Ignore leading '?' during validation.
         s = s.strip_prefix('?').unwrap_or(s);
-        if s.is_empty() || !s.chars().all(is_char_query) {
+        if s.is_empty() || !is_valid_component(s, is_char_query) {
           return Err(Error::InvalidQuery);
         }
         Ok(format!("?{s}"))
@@ -188,7 +187,7 @@ impl RelativeDIDUrl {
       .map(|mut s| {
         // Ignore leading '#' during validation.
         s = s.strip_prefix('#').unwrap_or(s);
-        if s.is_empty() || !s.chars().all(is_char_fragment) {
+        if s.is_empty() || !is_valid_component(s, is_char_fragment) {
           return Err(Error::InvalidFragment);
         }
         Ok(format!("#{s}"))
@@ -196,6 +195,38 @@ impl RelativeDIDUrl {
       .transpose()?;
     Ok(())
   }
+
+  /// Parses the path, query and fragment of a relative DID URL, each starting with its delimiter.
+  ///
+  /// Everything after the first '#' is the fragment and everything between the first '?' and the fragment is the
+  /// query, so both may contain a '?', but neither contains a '#'.
+  fn parse(input: &str) -> Result<Self, Error> {
+    let (input, fragment): (&str, Option<&str>) = match input.split_once('#') {
+      Some((input, fragment)) => (input, Some(fragment)),
+      None => (input, None),
+    };
+    let (path, query): (&str, Option<&str>) = match input.split_once('?') {
+      Some((path, query)) => (path, Some(query)),
+      None => (input, None),
+    };
+
+    // Restore the delimiters, so that a leading '?' or '#' that is part of a component is not ignored by the setters.
+    let mut url: Self = Self::new();
+    url.set_path(Some(path))?;
+    url.set_query(
+      query
+        .filter(|query| !query.is_empty())
+        .map(|query| format!("?{query}"))
+        .as_deref(),
+    )?;
+    url.set_fragment(
+      fragment
+        .filter(|fragment| !fragment.is_empty())
+        .map(|fragment| format!("#{fragment}"))
+        .as_deref(),
+    )?;
+    Ok(url)
+  }
 }
 
 impl Display for RelativeDIDUrl {
@@ -278,31 +309,22 @@ impl DIDUrl {
   }
 
   /// Parse a [`DIDUrl`] from a string.
+  ///
+  /// The path, query and fragment are validated individually and an error specific to the first invalid component is
+  /// returned. Everything after the first `#` is part of the fragment, which may itself contain a `?`, so a query
+  /// cannot follow a fragment. Percent-encoded octets (`%` followed by two hexadecimal digits) are accepted in every
+  /// component, while a malformed percent-encoding is rejected.
+  ///
+  /// The string representation of a parsed [`DIDUrl`] parses to an equal [`DIDUrl`].
   pub fn parse(input: impl AsRef<str>) -> Result<Self, Error> {
-    let did_url: BaseDIDUrl = BaseDIDUrl::parse(input)?;
-    Self::from_base_did_url(did_url)
-  }
-
-  fn from_base_did_url(did_url: BaseDIDUrl) -> Result<Self, Error> {
-    // Extract relative DID URL
-    let url: RelativeDIDUrl = {
-      let mut url: RelativeDIDUrl = RelativeDIDUrl::new();
-      url.set_path(Some(did_url.path()))?;
-      url.set_query(did_url.query())?;
-      url.set_fragment(did_url.fragment())?;
-      url
-    };
+    let input: &str = input.as_ref();
+    // The method-specific id cannot contain a delimiter, so the first one starts the relative DID URL.
+    let index: usize = input.find(['/', '?', '#']).unwrap_or(input.len());
 
-    // Extract base DID
-    let did: CoreDID = {
-      let mut base_did: BaseDIDUrl = did_url;
-      base_did.set_path("");
-      base_did.set_query(None);
-      base_did.set_fragment(None);
-      CoreDID::try_from(base_did).map_err(|_| Error::Other("invalid DID"))?
-    };
-
-    Ok(Self { did, url })
+    Ok(Self {
+      did: CoreDID::parse(&input[..index])?,
+      url: RelativeDIDUrl::parse(&input[index..])?,
+    })
   }
 
   /// Returns the [`did`][CoreDID].
@@ -386,9 +408,26 @@ impl DIDUrl {
       return Err(Error::InvalidPath);
     }
 
-    // Parse DID Url.
-    let base_did_url: BaseDIDUrl = BaseDIDUrl::parse(self.to_string())?.join(segment)?;
-    Self::from_base_did_url(base_did_url)
+    // Resolve the segment as a relative reference, see https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.2.
+    let relative: RelativeDIDUrl = RelativeDIDUrl::parse(segment)?;
+    let mut url: RelativeDIDUrl = self.url.clone();
+    match relative.path {
+      Some(path) => {
+        url.path = Some(remove_dot_segments(&path));
+        url.query = relative.query;
+      }
+      None => {
+        if relative.query.is_some() {
+          url.query = relative.query;
+        }
+      }
+    }
+    url.fragment = relative.fragment;
+
+    Ok(Self {
+      did: self.did.clone(),
+      url,
+    })
   }
 
   /// Maps a [`DIDUrl`] by applying a function to the [`CoreDID`] part of the [`DIDUrl`], the [`RelativeDIDUrl`]
@@ -519,10 +558,32 @@ impl KeyComparable for DIDUrl {
 #[inline(always)]
 #[rustfmt::skip]
 pub(crate) const fn is_char_path(ch: char) -> bool {
-  // Allow percent encoding or not?
+  // Percent-encoded octets are checked by `is_valid_component`.
   is_char_method_id(ch) || matches!(ch, '~' | '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '=' | '@' | '/' /* | '%' */)
 }
 
+/// Removes the `.` and `..` segments of an absolute path, see
+/// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.4).
+fn remove_dot_segments(path: &str) -> String {
+  let segments: Vec<&str> = path.split('/').skip(1).collect();
+  let mut output: Vec<&str> = Vec::with_capacity(segments.len());
+  for (index, segment) in segments.iter().enumerate() {
+    match *segment {
+      "." | ".." => {
+        if *segment == ".." {
+          output.pop();
+        }
+        // A trailing dot segment leaves the path ending with a '/'.
+        if index + 1 == segments.len() {
+          output.push("");
+        }
+      }
+      segment => output.push(segment),
+    }
+  }
+  format!("/{}", output.join("/"))
+}
+
 /// Checks whether a character satisfies DID Url query constraints.
 #[inline(always)]
 pub(crate) const fn is_char_query(ch: char) -> bool {
@@ -763,7 +824,118 @@ mod tests {
     assert!(matches!(relative_url.set_fragment(Some("#fragment\"other\"")), Err(Error::InvalidFragment)));
   }
 
+  #[test]
+  fn test_component_order() {
+    let did: &str = "did:iota:0xf29dd16310c2100fd1bf568b345fb1cc14d71caa3bd9b5ad735d2bd6d455ca3b";
+
+    let did_url: DIDUrl = DIDUrl::parse(format!("{did}?service=x#frag")).unwrap();
+    assert_eq!(did_url.query(), Some("service=x"));
+    assert_eq!(did_url.fragment(), Some("frag"));
+    assert_eq!(did_url.to_string(), format!("{did}?service=x#frag"));
+
+    // Everything after the first '#' is the fragment, which may contain '?', so a query cannot follow it.
+    let did_url: DIDUrl = DIDUrl::parse(format!("{did}#frag?service=x")).unwrap();
+    assert_eq!(did_url.query(), None);
+    assert_eq!(did_url.fragment(), Some("frag?service=x"));
+    assert_eq!(did_url.query_pairs().count(), 0);
+
+    // Only the first '?' is the query delimiter.
+    let did_url: DIDUrl = DIDUrl::parse(format!("{did}??service=x")).unwrap();
+    assert_eq!(did_url.query(), Some("?service=x"));
+    assert_eq!(did_url.to_string(), format!("{did}??service=x"));
+    assert_eq!(DIDUrl::parse(did_url.to_string()).unwrap(), did_url);
+
+    // An empty query is ignored.
+    for (input, expected) in [
+      (format!("{did}?"), did.to_owned()),
+      (format!("{did}?#frag"), format!("{did}#frag")),
+      (format!("{did}/path?"), format!("{did}/path")),
+    ] {
+      let did_url: DIDUrl = DIDUrl::parse(input).unwrap();
+      assert_eq!(did_url.query(), None);
+      assert_eq!(did_url.to_string(), expected);
+    }
+
+    // A fragment cannot contain another '#'.
+    assert!(matches!(
+      DIDUrl::parse(format!("{did}#frag#ment")),
+      Err(Error::InvalidFragment)
+    ));
+  }
+
+  #[test]
+  fn test_percent_encoding() {
+    let did: &str = "did:iota:0xf29dd16310c2100fd1bf568b345fb1cc14d71caa3bd9b5ad735d2bd6d455ca3b";
+
+    let input: String = format!("{did}/path%20to?service=linked%20domain#key%2D1");
+    let did_url: DIDUrl = DIDUrl::parse(&input).unwrap();
+    assert_eq!(did_url.path(), Some("/path%20to"));
+    assert_eq!(did_url.query(), Some("service=linked%20domain"));
+    assert_eq!(did_url.fragment(), Some("key%2D1"));
+    assert_eq!(did_url.to_string(), input);
+    assert_eq!(DIDUrl::parse(did_url.to_string()).unwrap(), did_url);
+
+    let did_url: DIDUrl = DIDUrl::parse("did:example:a%00?a=%00").unwrap();
+    assert_eq!(did_url.did().method_id(), "a%00");
+    assert_eq!(did_url.query(), Some("a=%00"));
+
+    let did_url: DIDUrl = DIDUrl::parse(did).unwrap();
+    assert_eq!(did_url.join("#key%2D1").unwrap().fragment(), Some("key%2D1"));
+    let mut relative_url: RelativeDIDUrl = RelativeDIDUrl::new();
+    relative_url.set_query(Some("?a=%20")).unwrap();
+    assert_eq!(relative_url.query(), Some("a=%20"));
+
+    for malformed in ["%2", "%zz", "%"] {
+      assert!(matches!(
+        DIDUrl::parse(format!("{did}/path{malformed}")),
+        Err(Error::InvalidPath)
+      ));
+      assert!(matches!(
+        DIDUrl::parse(format!("{did}?service={malformed}")),
+        Err(Error::InvalidQuery)
+      ));
+      assert!(matches!(
+        DIDUrl::parse(format!("{did}?service=x#key{malformed}")),
+        Err(Error::InvalidFragment)
+      ));
+      assert!(matches!(
+        did_url.join(format!("#key{malformed}")),
+        Err(Error::InvalidFragment)
+      ));
+      assert!(matches!(
+        relative_url.set_path(Some(&format!("/a{malformed}"))),
+        Err(Error::InvalidPath)
+      ));
+      assert!(matches!(
+        relative_url.set_query(Some(&format!("?a={malformed}"))),
+        Err(Error::InvalidQuery)
+      ));
+      assert!(matches!(
+        relative_url.set_fragment(Some(&format!("#a{malformed}"))),
+        Err(Error::InvalidFragment)
+      ));
+    }
+  }
+
+  #[test]
+  fn test_join_dot_segments() {
+    let did_url: DIDUrl = DIDUrl::parse("did:example:1234/a/b/c?query=1#fragment").unwrap();
+    assert_eq!(did_url.join("/a/./b/../d").unwrap().to_string(), "did:example:1234/a/d");
+    assert_eq!(did_url.join("/a/b/..").unwrap().to_string(), "did:example:1234/a/");
+    assert_eq!(
+      did_url.join("?other=2").unwrap().to_string(),
+      "did:example:1234/a/b/c?other=2"
+    );
+  }
+
   proptest::proptest! {
+    #[test]
+    fn test_fuzz_parse_round_trip(s in "did:example:[a-z0-9]{1,8}[/?#a-z0-9=&%]{0,16}") {
+      if let Ok(did_url) = DIDUrl::parse(&s) {
+        assert_eq!(DIDUrl::parse(did_url.to_string()).unwrap(), did_url);
+      }
+    }
+
     #[test]
     fn test_fuzz_join_no_panic(s in "\\PC*") {
       let did_url = DIDUrl::parse("did:example:1234567890").unwrap();
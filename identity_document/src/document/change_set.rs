@@ -0,0 +1,259 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::DIDUrl;
+use identity_verification::MethodRef;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+
+use crate::document::CoreDocument;
+use crate::service::Service;
+
+/// A structured summary of the differences between two versions of a [`CoreDocument`].
+///
+/// See [`CoreDocument::changes_since`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DocumentChangeSet {
+  /// Identifiers of the verification methods that were added.
+  pub added_methods: Vec<DIDUrl>,
+  /// Identifiers of the verification methods that were removed.
+  pub removed_methods: Vec<DIDUrl>,
+  /// Identifiers of the verification methods whose content, scope or verification relationships changed,
+  /// e.g. due to a key rotation.
+  pub changed_methods: Vec<DIDUrl>,
+  /// Identifiers of the services that were added.
+  pub added_services: Vec<DIDUrl>,
+  /// Identifiers of the services that were removed.
+  pub removed_services: Vec<DIDUrl>,
+  /// Identifiers of the services whose content changed.
+  pub changed_services: Vec<DIDUrl>,
+}
+
+impl DocumentChangeSet {
+  /// Returns `true` if no methods or services changed.
+  pub fn is_empty(&self) -> bool {
+    self.added_methods.is_empty()
+      && self.removed_methods.is_empty()
+      && self.changed_methods.is_empty()
+      && self.added_services.is_empty()
+      && self.removed_services.is_empty()
+      && self.changed_services.is_empty()
+  }
+
+  /// Computes the changes that turn `previous` into `current`.
+  pub(crate) fn between(previous: &CoreDocument, current: &CoreDocument) -> Self {
+    let previous_methods: Vec<MethodEntry<'_>> = MethodEntry::all(previous);
+    let current_methods: Vec<MethodEntry<'_>> = MethodEntry::all(current);
+    let (added_methods, removed_methods, changed_methods) =
+      diff(&previous_methods, &current_methods, |entry| entry.method.id());
+
+    let previous_services: Vec<&Service> = previous.service().iter().collect();
+    let current_services: Vec<&Service> = current.service().iter().collect();
+    let (added_services, removed_services, changed_services) =
+      diff(&previous_services, &current_services, |service| service.id());
+
+    Self {
+      added_methods,
+      removed_methods,
+      changed_methods,
+      added_services,
+      removed_services,
+      changed_services,
+    }
+  }
+}
+
+/// A verification method of a document together with its scope and the relationships referencing it.
+#[derive(PartialEq)]
+struct MethodEntry<'a> {
+  method: &'a VerificationMethod,
+  scope: MethodScope,
+  references: Vec<MethodRelationship>,
+}
+
+impl<'a> MethodEntry<'a> {
+  fn all(document: &'a CoreDocument) -> Vec<Self> {
+    let relationships = [
+      (MethodRelationship::Authentication, document.authentication()),
+      (MethodRelationship::AssertionMethod, document.assertion_method()),
+      (MethodRelationship::KeyAgreement, document.key_agreement()),
+      (
+        MethodRelationship::CapabilityDelegation,
+        document.capability_delegation(),
+      ),
+      (
+        MethodRelationship::CapabilityInvocation,
+        document.capability_invocation(),
+      ),
+    ];
+
+    let general_methods = document.verification_method().iter().map(|method| MethodEntry {
+      method,
+      scope: MethodScope::VerificationMethod,
+      references: relationships
+        .iter()
+        .filter(|(_, method_refs)| {
+          method_refs
+            .iter()
+            .any(|method_ref| matches!(method_ref, MethodRef::Refer(id) if id == method.id()))
+        })
+        .map(|(relationship, _)| *relationship)
+        .collect(),
+    });
+
+    let embedded_methods = relationships.iter().flat_map(|(relationship, method_refs)| {
+      method_refs.iter().filter_map(|method_ref| match method_ref {
+        MethodRef::Embed(method) => Some(MethodEntry {
+          method,
+          scope: MethodScope::VerificationRelationship(*relationship),
+          references: Vec::new(),
+        }),
+        MethodRef::Refer(_) => None,
+      })
+    });
+
+    general_methods.chain(embedded_methods).collect()
+  }
+}
+
+/// Returns the ids of the added, removed and changed entries, in document order.
+fn diff<T: PartialEq>(
+  previous: &[T],
+  current: &[T],
+  id: impl Fn(&T) -> &DIDUrl,
+) -> (Vec<DIDUrl>, Vec<DIDUrl>, Vec<DIDUrl>) {
+  let find = |entries: &[T], entry: &T| entries.iter().position(|other| id(other) == id(entry));
+
+  let mut added: Vec<DIDUrl> = Vec::new();
+  let mut changed: Vec<DIDUrl> = Vec::new();
+  for entry in current {
+    match find(previous, entry) {
+      None => added.push(id(entry).clone()),
+      Some(index) if previous[index] != *entry => changed.push(id(entry).clone()),
+      Some(_) => {}
+    }
+  }
+  let removed: Vec<DIDUrl> = previous
+    .iter()
+    .filter(|entry| find(current, entry).is_none())
+    .map(|entry| id(entry).clone())
+    .collect();
+
+  (added, removed, changed)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_did::CoreDID;
+  use identity_did::DID;
+  use identity_verification::MethodData;
+  use identity_verification::MethodType;
+
+  use super::*;
+
+  fn method(did: &CoreDID, fragment: &str, key: u8) -> VerificationMethod {
+    VerificationMethod::builder(Object::new())
+      .id(did.to_url().join(fragment).unwrap())
+      .controller(did.clone())
+      .type_(MethodType::ED25519_VERIFICATION_KEY_2018)
+      .data(MethodData::new_multibase([key; 32]))
+      .build()
+      .unwrap()
+  }
+
+  fn service(did: &CoreDID, fragment: &str, endpoint: &str) -> Service {
+    Service::builder(Object::new())
+      .id(did.to_url().join(fragment).unwrap())
+      .type_("LinkedDomains")
+      .service_endpoint(Url::parse(endpoint).unwrap())
+      .build()
+      .unwrap()
+  }
+
+  fn document() -> CoreDocument {
+    let did: CoreDID = "did:example:1234".parse().unwrap();
+    CoreDocument::builder(Object::new())
+      .id(did.clone())
+      .verification_method(method(&did, "#key-1", 1))
+      .verification_method(method(&did, "#key-2", 2))
+      .authentication(method(&did, "#auth-key", 3))
+      .service(service(&did, "#linked-domain", "https://example.com"))
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_no_changes() {
+    let document: CoreDocument = document();
+    let changes: DocumentChangeSet = document.changes_since(&document.clone());
+    assert!(changes.is_empty());
+    assert_eq!(changes, DocumentChangeSet::default());
+  }
+
+  #[test]
+  fn test_added_service() {
+    let previous: CoreDocument = document();
+    let mut current: CoreDocument = previous.clone();
+    let added: Service = service(previous.id(), "#other-domain", "https://example.org");
+    current.insert_service(added.clone()).unwrap();
+
+    let changes: DocumentChangeSet = current.changes_since(&previous);
+    assert_eq!(changes.added_services, vec![added.id().clone()]);
+    assert!(changes.removed_services.is_empty());
+    assert!(changes.changed_services.is_empty());
+    assert!(
+      changes.added_methods.is_empty() && changes.removed_methods.is_empty() && changes.changed_methods.is_empty()
+    );
+
+    // Viewed the other way around, the service was removed.
+    let changes: DocumentChangeSet = previous.changes_since(&current);
+    assert_eq!(changes.removed_services, vec![added.id().clone()]);
+    assert!(changes.added_services.is_empty());
+  }
+
+  #[test]
+  fn test_removed_method() {
+    let previous: CoreDocument = document();
+    let mut current: CoreDocument = previous.clone();
+    let removed: DIDUrl = previous.id().to_url().join("#auth-key").unwrap();
+    current.remove_method(&removed).unwrap();
+
+    let changes: DocumentChangeSet = current.changes_since(&previous);
+    assert_eq!(changes.removed_methods, vec![removed]);
+    assert!(changes.added_methods.is_empty());
+    assert!(changes.changed_methods.is_empty());
+    assert!(changes.added_services.is_empty() && changes.removed_services.is_empty());
+  }
+
+  #[test]
+  fn test_rotated_key() {
+    let previous: CoreDocument = document();
+    let mut current: CoreDocument = previous.clone();
+    let rotated: DIDUrl = previous.id().to_url().join("#key-1").unwrap();
+    *current.resolve_method_mut(&rotated, None).unwrap().data_mut() = MethodData::new_multibase([9; 32]);
+
+    let changes: DocumentChangeSet = current.changes_since(&previous);
+    assert_eq!(changes.changed_methods, vec![rotated]);
+    assert!(changes.added_methods.is_empty());
+    assert!(changes.removed_methods.is_empty());
+  }
+
+  #[test]
+  fn test_changed_relationship() {
+    let previous: CoreDocument = document();
+    let mut current: CoreDocument = previous.clone();
+    current
+      .attach_method_relationship("#key-2", MethodRelationship::AssertionMethod)
+      .unwrap();
+
+    let changes: DocumentChangeSet = current.changes_since(&previous);
+    assert_eq!(
+      changes.changed_methods,
+      vec![previous.id().to_url().join("#key-2").unwrap()]
+    );
+  }
+}
@@ -21,6 +21,7 @@ use identity_core::convert::FmtJson;
 use serde::Serializer;
 
 use crate::document::DocumentBuilder;
+use crate::document::DocumentChangeSet;
 use crate::error::Error;
 use crate::error::Result;
 use crate::service::Service;
@@ -28,10 +29,15 @@ use crate::utils::DIDUrlQuery;
 use crate::utils::Queryable;
 use crate::verifiable::JwsVerificationOptions;
 use identity_did::CoreDID;
+use identity_did::DIDKey;
+use identity_did::DIDKeyType;
 use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_verification::MethodData;
 use identity_verification::MethodRef;
 use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
+use identity_verification::MethodType;
 use identity_verification::VerificationMethod;
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -307,6 +313,42 @@ impl CoreDocument {
     })
   }
 
+  /// Expands a [`DIDKey`] into its DID Document, following the
+  /// [`did:key` method specification](https://w3c-ccg.github.io/did-method-key/#document-creation-algorithm).
+  ///
+  /// The document contains a single verification method of the type given by [`DIDKeyType::method_type`], whose
+  /// `publicKeyMultibase` and fragment are the method-specific identifier of the DID. A signing key is referenced by
+  /// the `authentication`, `assertionMethod`, `capabilityDelegation` and `capabilityInvocation` relationships, while a
+  /// key agreement key is only referenced by `keyAgreement`. No key agreement method is derived from an Ed25519 key.
+  pub fn from_did_key(did: &DIDKey) -> Self {
+    let key_type: DIDKeyType = did.key_type();
+    let did_url: DIDUrl = did
+      .to_url()
+      .join(format!("#{}", did.multibase()))
+      .expect("a multibase string is a valid fragment");
+    let method: VerificationMethod = VerificationMethod::builder(Object::new())
+      .id(did_url.clone())
+      .controller(did.clone().into())
+      .type_(MethodType::custom(key_type.method_type()))
+      .data(MethodData::PublicKeyMultibase(did.multibase().to_owned()))
+      .build()
+      .expect("the method id has a fragment");
+
+    let builder: DocumentBuilder = CoreDocument::builder(Object::new())
+      .id(did.clone().into())
+      .verification_method(method);
+    let builder: DocumentBuilder = if key_type.is_key_agreement() {
+      builder.key_agreement(did_url)
+    } else {
+      builder
+        .authentication(did_url.clone())
+        .assertion_method(did_url.clone())
+        .capability_delegation(did_url.clone())
+        .capability_invocation(did_url)
+    };
+    builder.build().expect("the document is valid by construction")
+  }
+
   /// Returns a reference to the `CoreDocument` id.
   pub fn id(&self) -> &CoreDID {
     &self.data.id
@@ -527,6 +569,114 @@ impl CoreDocument {
     self.data.service.remove(id)
   }
 
+  /// Adds the verification methods, verification relationships and services of `other` that are missing from this
+  /// document.
+  ///
+  /// Entries that are present in both documents with identical content are left as they are. Other properties of
+  /// `other`, such as its controllers, are ignored.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if `other` has a different `id`, or if a method or service of `other` shares its identifier with
+  /// a different method or service in this document. This document is left unchanged on error.
+  pub fn merge_nonconflicting(&mut self, other: &CoreDocument) -> Result<()> {
+    if self.id() != other.id() {
+      return Err(Error::InvalidDocument(
+        "cannot merge documents with different ids",
+        None,
+      ));
+    }
+
+    let mut merged: CoreDocument = self.clone();
+
+    for method in other.verification_method().iter() {
+      merged.merge_method(method, MethodScope::VerificationMethod)?;
+    }
+
+    for (relationship, method_refs) in [
+      (MethodRelationship::Authentication, other.authentication()),
+      (MethodRelationship::AssertionMethod, other.assertion_method()),
+      (MethodRelationship::KeyAgreement, other.key_agreement()),
+      (MethodRelationship::CapabilityDelegation, other.capability_delegation()),
+      (MethodRelationship::CapabilityInvocation, other.capability_invocation()),
+    ] {
+      for method_ref in method_refs.iter() {
+        match method_ref {
+          MethodRef::Embed(method) => {
+            merged.merge_method(method, MethodScope::VerificationRelationship(relationship))?;
+          }
+          MethodRef::Refer(did_url) => {
+            // A reference must not point to a method that is embedded in this document.
+            if merged
+              .resolve_method(did_url, Some(MethodScope::VerificationMethod))
+              .is_none()
+              && merged.resolve_method(did_url, None).is_some()
+            {
+              return Err(Error::MergeConflict(did_url.clone()));
+            }
+            let method_refs: &mut OrderedSet<MethodRef> = match relationship {
+              MethodRelationship::Authentication => &mut merged.data.authentication,
+              MethodRelationship::AssertionMethod => &mut merged.data.assertion_method,
+              MethodRelationship::KeyAgreement => &mut merged.data.key_agreement,
+              MethodRelationship::CapabilityDelegation => &mut merged.data.capability_delegation,
+              MethodRelationship::CapabilityInvocation => &mut merged.data.capability_invocation,
+            };
+            method_refs.append(method_ref.clone());
+          }
+        }
+      }
+    }
+
+    for service in other.service().iter() {
+      match merged.service().query(service.id()) {
+        Some(existing) if existing == service => {}
+        Some(_) => return Err(Error::MergeConflict(service.id().clone())),
+        None => merged
+          .insert_service(service.clone())
+          .map_err(|_| Error::MergeConflict(service.id().clone()))?,
+      }
+    }
+
+    *self = merged;
+    Ok(())
+  }
+
+  /// Inserts `method` in the given `scope` unless an identical method already exists in that scope.
+  ///
+  /// An existing method is only identical if it is placed the same way, i.e. embedded in the same relationship or
+  /// listed in `verificationMethod`, rather than e.g. referenced from the relationship.
+  fn merge_method(&mut self, method: &VerificationMethod, scope: MethodScope) -> Result<()> {
+    let identical: bool = match scope {
+      MethodScope::VerificationMethod => self.data.verification_method.query(method.id()) == Some(method),
+      MethodScope::VerificationRelationship(relationship) => {
+        let method_refs: &OrderedSet<MethodRef> = match relationship {
+          MethodRelationship::Authentication => self.authentication(),
+          MethodRelationship::AssertionMethod => self.assertion_method(),
+          MethodRelationship::KeyAgreement => self.key_agreement(),
+          MethodRelationship::CapabilityDelegation => self.capability_delegation(),
+          MethodRelationship::CapabilityInvocation => self.capability_invocation(),
+        };
+        matches!(method_refs.query(method.id()), Some(MethodRef::Embed(existing)) if existing == method)
+      }
+    };
+    match self.resolve_method(method.id(), None) {
+      Some(_) if identical => Ok(()),
+      Some(_) => Err(Error::MergeConflict(method.id().clone())),
+      None => self
+        .insert_method(method.clone(), scope)
+        .map_err(|_| Error::MergeConflict(method.id().clone())),
+    }
+  }
+
+  /// Summarizes which verification methods and services were added, removed or changed in this document compared to
+  /// `previous`.
+  ///
+  /// Methods and services are matched by their `id`. A method counts as changed if its content, its scope or the
+  /// verification relationships referencing it differ, e.g. after a key rotation.
+  pub fn changes_since(&self, previous: &CoreDocument) -> DocumentChangeSet {
+    DocumentChangeSet::between(previous, self)
+  }
+
   /// Attaches the relationship to the method resolved by `method_query`.
   ///
   /// # Errors
@@ -1341,6 +1491,143 @@ mod tests {
     assert!(document.verification_method().query(method3.id()).is_none());
   }
 
+  #[test]
+  fn test_merge_nonconflicting() {
+    let controller: CoreDID = controller();
+    let service = |fragment: &str, endpoint: &str| -> Service {
+      ServiceBuilder::default()
+        .id(controller.to_url().join(fragment).unwrap())
+        .type_("test")
+        .service_endpoint(Url::parse(endpoint).unwrap())
+        .build()
+        .unwrap()
+    };
+
+    let mut document: CoreDocument = document();
+    document
+      .insert_service(service("#service-1", "https://example.com"))
+      .unwrap();
+
+    // Two clients independently extend the same base document.
+    let mut other: CoreDocument = document.clone();
+    other
+      .insert_method(method(&controller, "#key-5"), MethodScope::VerificationMethod)
+      .unwrap();
+    other
+      .insert_method(method(&controller, "#assert-key"), MethodScope::assertion_method())
+      .unwrap();
+    other
+      .attach_method_relationship("#key-1", MethodRelationship::CapabilityInvocation)
+      .unwrap();
+    other
+      .insert_service(service("#service-2", "https://example.org"))
+      .unwrap();
+    document
+      .insert_service(service("#service-3", "https://example.net"))
+      .unwrap();
+
+    document.merge_nonconflicting(&other).unwrap();
+    assert_eq!(
+      document.resolve_method("#key-5", Some(MethodScope::VerificationMethod)),
+      Some(&method(&controller, "#key-5"))
+    );
+    assert_eq!(
+      document.resolve_method("#assert-key", Some(MethodScope::assertion_method())),
+      Some(&method(&controller, "#assert-key"))
+    );
+    assert!(document
+      .resolve_method("#key-1", Some(MethodScope::capability_invocation()))
+      .is_some());
+    assert_eq!(document.service().len(), 3);
+    assert!(document.resolve_service("#service-2").is_some());
+
+    // Merging is idempotent.
+    let merged: CoreDocument = document.clone();
+    document.merge_nonconflicting(&other).unwrap();
+    assert_eq!(document, merged);
+  }
+
+  #[test]
+  fn test_merge_nonconflicting_rejects_conflicts() {
+    let controller: CoreDID = controller();
+    let base: CoreDocument = document();
+
+    // Same fragment, different key material.
+    let mut other: CoreDocument = base.clone();
+    *other.resolve_method_mut("#key-1", None).unwrap().data_mut() = MethodData::new_multibase([1; 32]);
+    let mut document: CoreDocument = base.clone();
+    assert!(matches!(
+      document.merge_nonconflicting(&other).unwrap_err(),
+      Error::MergeConflict(did_url) if did_url.fragment() == Some("key-1")
+    ));
+    assert_eq!(document, base);
+
+    // Same fragment, different scope.
+    let mut other: CoreDocument = base.clone();
+    let (auth_method, _) = other
+      .remove_method_and_scope(&controller.to_url().join("#auth-key").unwrap())
+      .unwrap();
+    other
+      .insert_method(auth_method, MethodScope::VerificationMethod)
+      .unwrap();
+    let mut document: CoreDocument = base.clone();
+    assert!(matches!(
+      document.merge_nonconflicting(&other).unwrap_err(),
+      Error::MergeConflict(_)
+    ));
+
+    // Same method, embedded in a relationship of one document but only referenced from it in the other.
+    let mut other: CoreDocument = base.clone();
+    let (key_method, _) = other
+      .remove_method_and_scope(&controller.to_url().join("#key-1").unwrap())
+      .unwrap();
+    other.insert_method(key_method, MethodScope::authentication()).unwrap();
+    let mut document: CoreDocument = base.clone();
+    document
+      .attach_method_relationship("#key-1", MethodRelationship::Authentication)
+      .unwrap();
+    let expected: CoreDocument = document.clone();
+    assert!(matches!(
+      document.merge_nonconflicting(&other).unwrap_err(),
+      Error::MergeConflict(did_url) if did_url.fragment() == Some("key-1")
+    ));
+    assert_eq!(document, expected);
+
+    // Same fragment used by a method and a service.
+    let mut other: CoreDocument = base.clone();
+    other
+      .insert_service(
+        ServiceBuilder::default()
+          .id(controller.to_url().join("#key-6").unwrap())
+          .type_("test")
+          .service_endpoint(Url::parse("https://example.com").unwrap())
+          .build()
+          .unwrap(),
+      )
+      .unwrap();
+    let mut document: CoreDocument = base.clone();
+    document
+      .insert_method(method(&controller, "#key-6"), MethodScope::VerificationMethod)
+      .unwrap();
+    let expected: CoreDocument = document.clone();
+    assert!(matches!(
+      document.merge_nonconflicting(&other).unwrap_err(),
+      Error::MergeConflict(_)
+    ));
+    assert_eq!(document, expected);
+
+    // Different DID.
+    let other: CoreDocument = CoreDocument::builder(Default::default())
+      .id("did:example:5678".parse().unwrap())
+      .build()
+      .unwrap();
+    let mut document: CoreDocument = base;
+    assert!(matches!(
+      document.merge_nonconflicting(&other).unwrap_err(),
+      Error::InvalidDocument(_, None)
+    ));
+  }
+
   #[test]
   fn test_service_updates() {
     let mut document = document();
@@ -1682,4 +1969,54 @@ mod tests {
       verifier(json);
     }
   }
+
+  #[test]
+  fn test_from_did_key() {
+    // Example from the did:key method specification, without the JSON-LD context and the optional key agreement
+    // method derived from the Ed25519 key.
+    const ED25519_DOCUMENT: &str = r#"{
+      "id": "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+      "verificationMethod": [{
+        "id": "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+        "type": "Ed25519VerificationKey2020",
+        "controller": "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+        "publicKeyMultibase": "z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+      }],
+      "authentication": [
+        "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+      ],
+      "assertionMethod": [
+        "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+      ],
+      "capabilityDelegation": [
+        "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+      ],
+      "capabilityInvocation": [
+        "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+      ]
+    }"#;
+
+    let ed25519: DIDKey = DIDKey::parse("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK").unwrap();
+    let document: CoreDocument = CoreDocument::from_did_key(&ed25519);
+    assert_eq!(document, CoreDocument::from_json(ED25519_DOCUMENT).unwrap());
+    let method: &VerificationMethod = &document.verification_method()[0];
+    assert_eq!(method.data().try_decode().unwrap()[2..], ed25519.public_key());
+
+    let x25519: DIDKey = DIDKey::parse("did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F").unwrap();
+    let document: CoreDocument = CoreDocument::from_did_key(&x25519);
+    let method_id: DIDUrl = x25519
+      .to_url()
+      .join("#z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F")
+      .unwrap();
+    let method: &VerificationMethod = document
+      .resolve_method(&method_id, Some(MethodScope::key_agreement()))
+      .unwrap();
+    assert_eq!(method.type_().as_str(), "X25519KeyAgreementKey2020");
+    assert_eq!(
+      method.data(),
+      &MethodData::PublicKeyMultibase(x25519.multibase().to_owned())
+    );
+    assert!(document.authentication().is_empty());
+    assert!(document.assertion_method().is_empty());
+  }
 }
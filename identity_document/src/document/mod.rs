@@ -6,7 +6,9 @@
 #![allow(clippy::module_inception)]
 
 pub use self::builder::DocumentBuilder;
+pub use self::change_set::DocumentChangeSet;
 pub use self::core_document::CoreDocument;
 
 mod builder;
+mod change_set;
 mod core_document;
@@ -33,6 +33,10 @@ pub enum Error {
   /// service.
   #[error("unable to insert service: the id is already in use")]
   InvalidServiceInsertion,
+  /// Caused by attempting to merge a document containing a method or service whose id is already used by a different
+  /// method or service.
+  #[error("unable to merge documents: conflicting entries for `{0}`")]
+  MergeConflict(identity_did::DIDUrl),
   /// Caused by an attempt to use a method's key material in an incompatible context.
   #[error("invalid key material")]
   InvalidKeyMaterial(#[source] identity_verification::Error),
@@ -11,6 +11,9 @@ use identity_core::common::OrderedSet;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson;
 
+use crate::error::Error;
+use crate::error::Result;
+
 /// A single URL, set, or map of endpoints specified in a [`Service`](crate::service::Service).
 ///
 /// [Specification](https://www.w3.org/TR/did-core/#dfn-serviceendpoint)
@@ -22,6 +25,48 @@ pub enum ServiceEndpoint {
   Map(IndexMap<String, OrderedSet<Url>>),
 }
 
+impl ServiceEndpoint {
+  /// Creates a [`ServiceEndpoint::Set`] from the given URLs.
+  ///
+  /// Duplicate URLs are ignored, the order of the remaining ones is preserved.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::InvalidService`] if no URL is given.
+  pub fn set(urls: impl IntoIterator<Item = Url>) -> Result<Self> {
+    let set: OrderedSet<Url> = urls.into_iter().collect();
+    if set.is_empty() {
+      return Err(Error::InvalidService("empty service endpoint set"));
+    }
+    Ok(ServiceEndpoint::Set(set))
+  }
+
+  /// Creates a [`ServiceEndpoint::Map`] from the given entries.
+  ///
+  /// The insertion order of the keys is preserved. If a key occurs more than once, the last entry wins.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::InvalidService`] if no entry is given or an entry has no URLs.
+  pub fn map<K, V>(entries: impl IntoIterator<Item = (K, V)>) -> Result<Self>
+  where
+    K: Into<String>,
+    V: IntoIterator<Item = Url>,
+  {
+    let map: IndexMap<String, OrderedSet<Url>> = entries
+      .into_iter()
+      .map(|(key, urls)| (key.into(), urls.into_iter().collect()))
+      .collect();
+    if map.is_empty() {
+      return Err(Error::InvalidService("empty service endpoint map"));
+    }
+    if map.values().any(OrderedSet::is_empty) {
+      return Err(Error::InvalidService("empty service endpoint map entry"));
+    }
+    Ok(ServiceEndpoint::Map(map))
+  }
+}
+
 impl From<Url> for ServiceEndpoint {
   fn from(url: Url) -> Self {
     ServiceEndpoint::One(url)
@@ -189,6 +234,45 @@ mod tests {
     assert_eq!(endpoint_map, ServiceEndpoint::from_json(&ser_endpoint_map).unwrap());
   }
 
+  #[test]
+  fn test_service_endpoint_constructors() {
+    let url1 = Url::parse("https://iota.org/").unwrap();
+    let url2 = Url::parse("wss://www.example.com/socketserver/").unwrap();
+
+    let endpoint_set: ServiceEndpoint = ServiceEndpoint::set([url1.clone(), url2.clone(), url1.clone()]).unwrap();
+    assert_eq!(
+      endpoint_set,
+      ServiceEndpoint::Set(OrderedSet::try_from(vec![url1.clone(), url2.clone()]).unwrap())
+    );
+    assert_eq!(
+      endpoint_set.to_json().unwrap(),
+      "[\"https://iota.org/\",\"wss://www.example.com/socketserver/\"]"
+    );
+
+    let endpoint_map: ServiceEndpoint =
+      ServiceEndpoint::map([("key", vec![url1.clone()]), ("apple", vec![url2.clone()])]).unwrap();
+    let ser_endpoint_map: String = endpoint_map.to_json().unwrap();
+    assert_eq!(
+      ser_endpoint_map,
+      r#"{"key":["https://iota.org/"],"apple":["wss://www.example.com/socketserver/"]}"#
+    );
+    assert_eq!(endpoint_map, ServiceEndpoint::from_json(&ser_endpoint_map).unwrap());
+
+    // INVALID: empty set, empty map and map entry without URLs.
+    assert!(matches!(
+      ServiceEndpoint::set(Vec::new()),
+      Err(Error::InvalidService(_))
+    ));
+    assert!(matches!(
+      ServiceEndpoint::map(Vec::<(String, Vec<Url>)>::new()),
+      Err(Error::InvalidService(_))
+    ));
+    assert!(matches!(
+      ServiceEndpoint::map([("key", vec![url1]), ("apple", vec![])]),
+      Err(Error::InvalidService(_))
+    ));
+  }
+
   #[test]
   fn test_service_endpoint_serde_fails() {
     // INVALID: empty
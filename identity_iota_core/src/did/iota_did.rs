@@ -47,6 +47,37 @@ impl IotaDID {
   /// The length of an Alias ID, which is a BLAKE2b-256 hash (32-bytes).
   pub(crate) const TAG_BYTES_LEN: usize = 32;
 
+  /// Test vectors for the derivation of IOTA DIDs, as `(output id, network name, DID)` triples.
+  ///
+  /// The tag of a DID is the Alias ID of the Alias Output that created it, which is the BLAKE2b-256 hash of the
+  /// output's id as defined in [TIP-18](https://github.com/iotaledger/tips/blob/main/tips/TIP-0018/tip-0018.md). It must
+  /// never change, since existing DIDs could otherwise no longer be resolved. The output ids are taken from the tests
+  /// of [bee](https://github.com/iotaledger/bee/blob/30cab4f02e9f5d72ffe137fd9eb09723b4f0fdb6/bee-block/tests/output_id.rs)
+  /// or are boundary values, and the tags were computed independently of this crate with the BLAKE2 reference
+  /// implementation, e.g. `hashlib.blake2b(bytes.fromhex(output_id), digest_size=32)` in Python.
+  pub const DERIVATION_TEST_VECTORS: [(&'static str, &'static str, &'static str); 4] = [
+    (
+      "0x00000000000000000000000000000000000000000000000000000000000000000000",
+      "iota",
+      "did:iota:0xcf077d276686ba64c0404b9eb2d15556782113c5a1985f262b70f9964d3bbd7f",
+    ),
+    (
+      "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00",
+      "iota",
+      "did:iota:0xf29dd16310c2100fd1bf568b345fb1cc14d71caa3bd9b5ad735d2bd6d455ca3b",
+    ),
+    (
+      "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00",
+      "atoi",
+      "did:iota:atoi:0xf29dd16310c2100fd1bf568b345fb1cc14d71caa3bd9b5ad735d2bd6d455ca3b",
+    ),
+    (
+      "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f00",
+      "smr",
+      "did:iota:smr:0xa9c0a0cc4704a11b4e778b400886717ce068b1a83f5d26ff293e8904469e7d94",
+    ),
+  ];
+
   /// Convert a `CoreDID` reference to an `IotaDID` reference without checking the referenced value.
   ///  
   /// # Warning
@@ -156,6 +187,21 @@ impl IotaDID {
     Self::denormalized_components(self.method_id()).1
   }
 
+  /// Returns the decoded bytes of the tag of the `DID`, i.e. the raw Alias ID.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use identity_iota_core::NetworkName;
+  /// # use identity_iota_core::IotaDID;
+  /// #
+  /// let did = IotaDID::new(&[1;32], &NetworkName::try_from("smr").unwrap());
+  /// assert_eq!(did.tag_bytes(), [1;32]);
+  pub fn tag_bytes(&self) -> [u8; Self::TAG_BYTES_LEN] {
+    prefix_hex::decode(self.tag_str())
+      .expect("being able to successfully decode the tag should be checked during DID creation")
+  }
+
   // ===========================================================================
   // Validation
   // ===========================================================================
@@ -334,9 +380,7 @@ mod __iota_did_client {
   impl From<&IotaDID> for AliasId {
     /// Creates an [`AliasId`] from the DID tag.
     fn from(did: &IotaDID) -> Self {
-      let tag_bytes: [u8; IotaDID::TAG_BYTES_LEN] = prefix_hex::decode(did.tag_str())
-        .expect("being able to successfully decode the tag should be checked during DID creation");
-      AliasId::new(tag_bytes)
+      AliasId::new(did.tag_bytes())
     }
   }
 }
@@ -558,6 +602,47 @@ mod tests {
     );
   }
 
+  #[test]
+  fn normalized_equality_ordering_and_hash() {
+    use identity_core::convert::FromJson;
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::Hash;
+    use std::hash::Hasher;
+
+    fn hash(did: &IotaDID) -> u64 {
+      let mut hasher = DefaultHasher::new();
+      did.hash(&mut hasher);
+      hasher.finish()
+    }
+
+    let explicit_network: String = format!(
+      "did:{}:{}:{}",
+      IotaDID::METHOD,
+      IotaDID::DEFAULT_NETWORK,
+      VALID_ALIAS_ID_STR
+    );
+    let implicit_network: String = format!("did:{}:{}", IotaDID::METHOD, VALID_ALIAS_ID_STR);
+
+    let dids: [IotaDID; 4] = [
+      IotaDID::parse(&explicit_network).unwrap(),
+      IotaDID::parse(&implicit_network).unwrap(),
+      IotaDID::try_from(CoreDID::parse(&explicit_network).unwrap()).unwrap(),
+      IotaDID::from_json(&format!("\"{explicit_network}\"")).unwrap(),
+    ];
+    for did in dids.iter() {
+      assert_eq!(did, &dids[0]);
+      assert_eq!(did.cmp(&dids[0]), Ordering::Equal);
+      assert_eq!(hash(did), hash(&dids[0]));
+    }
+    assert_eq!(dids.into_iter().collect::<HashSet<IotaDID>>().len(), 1);
+
+    // DIDs on other networks remain distinct.
+    let smr_did: IotaDID = IotaDID::parse(format!("did:{}:smr:{}", IotaDID::METHOD, VALID_ALIAS_ID_STR)).unwrap();
+    assert_ne!(smr_did, IotaDID::parse(&implicit_network).unwrap());
+  }
+
   #[test]
   fn parse_valid() {
     for did_str in VALID_IOTA_DID_STRINGS.iter() {
@@ -565,6 +650,19 @@ mod tests {
     }
   }
 
+  #[test]
+  fn tag_bytes() {
+    let network_name: NetworkName = NetworkName::try_from("smr").unwrap();
+    let bytes: [u8; IotaDID::TAG_BYTES_LEN] = core::array::from_fn(|index| index as u8);
+    let did: IotaDID = IotaDID::new(&bytes, &network_name);
+    assert_eq!(did.tag_bytes(), bytes);
+    assert_eq!(IotaDID::parse(did.as_str()).unwrap().tag_bytes(), bytes);
+    assert_eq!(
+      IotaDID::placeholder(&network_name).tag_bytes(),
+      [0; IotaDID::TAG_BYTES_LEN]
+    );
+  }
+
   #[test]
   fn parse_invalid() {
     let execute_assertions = |valid_alias_id: &str| {
@@ -611,6 +709,18 @@ mod tests {
   // Test constructors with randomly generated input
   // ===========================================================================================================================
 
+  #[cfg(feature = "iota-client")]
+  #[test]
+  fn derivation_test_vectors() {
+    for (output_id, network, expected) in IotaDID::DERIVATION_TEST_VECTORS {
+      let output_id = iota_sdk::types::block::output::OutputId::from_str(output_id).unwrap();
+      let alias_id = iota_sdk::types::block::output::AliasId::from(&output_id);
+      let did: IotaDID = IotaDID::new(&alias_id, &NetworkName::try_from(network).unwrap());
+      assert_eq!(did.as_str(), expected);
+      assert_eq!(IotaDID::parse(expected).unwrap(), did);
+    }
+  }
+
   #[cfg(feature = "iota-client")]
   fn arbitrary_alias_id() -> impl Strategy<Value = iota_sdk::types::block::output::AliasId> {
     (
@@ -0,0 +1,160 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use std::collections::HashMap;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::convert::FromJson;
+
+use crate::IotaDocument;
+use crate::Result;
+
+/// The SHA-256 hash of the canonical serialization of an [`IotaDocument`], see [`IotaDocument::canonicalize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ContentHash([u8; SHA256_LEN]);
+
+impl ContentHash {
+  /// Computes the [`ContentHash`] of the given `document`.
+  pub fn new(document: &IotaDocument) -> Result<Self> {
+    document.canonicalize().map(|bytes| Self::from_canonical_bytes(&bytes))
+  }
+
+  /// Returns the hash as a byte array.
+  pub fn as_bytes(&self) -> &[u8; SHA256_LEN] {
+    &self.0
+  }
+
+  fn from_canonical_bytes(bytes: &[u8]) -> Self {
+    let mut hash: [u8; SHA256_LEN] = Default::default();
+    SHA256(bytes, &mut hash);
+    Self(hash)
+  }
+}
+
+impl From<[u8; SHA256_LEN]> for ContentHash {
+  fn from(bytes: [u8; SHA256_LEN]) -> Self {
+    Self(bytes)
+  }
+}
+
+impl Display for ContentHash {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(&prefix_hex::encode(self.0))
+  }
+}
+
+/// An in-memory, content-addressed store of [`IotaDocument`]s.
+///
+/// Documents are stored as their canonical serialization and referenced by its [`ContentHash`], so storing
+/// multiple versions of a document keeps only one copy of each distinct version.
+#[derive(Clone, Debug, Default)]
+pub struct DocumentStore {
+  blobs: HashMap<ContentHash, Vec<u8>>,
+}
+
+impl DocumentStore {
+  /// Creates an empty [`DocumentStore`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Stores `document` unless an equal document is already stored, returning the [`ContentHash`] that references it.
+  pub fn put(&mut self, document: &IotaDocument) -> Result<ContentHash> {
+    let bytes: Vec<u8> = document.canonicalize()?;
+    let hash: ContentHash = ContentHash::from_canonical_bytes(&bytes);
+    self.blobs.entry(hash).or_insert(bytes);
+
+    Ok(hash)
+  }
+
+  /// Returns the document referenced by `hash`, if it is stored.
+  pub fn get(&self, hash: &ContentHash) -> Option<IotaDocument> {
+    self.blobs.get(hash).map(|bytes| {
+      IotaDocument::from_json_slice(bytes).expect("stored documents are serialized by `IotaDocument::canonicalize`")
+    })
+  }
+
+  /// Returns whether a document referenced by `hash` is stored.
+  pub fn contains(&self, hash: &ContentHash) -> bool {
+    self.blobs.contains_key(hash)
+  }
+
+  /// Returns the number of distinct documents stored.
+  pub fn len(&self) -> usize {
+    self.blobs.len()
+  }
+
+  /// Returns whether no documents are stored.
+  pub fn is_empty(&self) -> bool {
+    self.blobs.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Timestamp;
+  use identity_verification::MethodScope;
+
+  use crate::document::test_utils::generate_method;
+  use crate::IotaDID;
+
+  use super::*;
+
+  fn document() -> IotaDocument {
+    let did: IotaDID = "did:iota:0xAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"
+      .parse()
+      .unwrap();
+    let mut document: IotaDocument = IotaDocument::new_with_id(did.clone());
+    document.metadata.created = Some(Timestamp::parse("2020-01-02T00:00:00Z").unwrap());
+    document.metadata.updated = Some(Timestamp::parse("2020-01-02T00:00:00Z").unwrap());
+    document
+      .insert_method(generate_method(&did, "#key-1"), MethodScope::VerificationMethod)
+      .unwrap();
+    document
+  }
+
+  #[test]
+  fn test_put_same_document_twice() {
+    let document: IotaDocument = document();
+    let mut store: DocumentStore = DocumentStore::new();
+    assert!(store.is_empty());
+
+    let hash: ContentHash = store.put(&document).unwrap();
+    assert_eq!(store.put(&document.clone()).unwrap(), hash);
+    assert_eq!(store.len(), 1);
+    assert_eq!(hash, ContentHash::new(&document).unwrap());
+    assert_eq!(store.get(&hash).unwrap(), document);
+
+    // An equal document deserialized from JSON references the same blob.
+    let reparsed: IotaDocument = IotaDocument::from_json(&document.to_string()).unwrap();
+    assert_eq!(store.put(&reparsed).unwrap(), hash);
+    assert_eq!(store.len(), 1);
+  }
+
+  #[test]
+  fn test_put_distinct_documents() {
+    let document: IotaDocument = document();
+    let mut updated: IotaDocument = document.clone();
+    updated.metadata.updated = Some(Timestamp::parse("2020-01-03T00:00:00Z").unwrap());
+
+    let mut store: DocumentStore = DocumentStore::new();
+    let hash: ContentHash = store.put(&document).unwrap();
+    let updated_hash: ContentHash = store.put(&updated).unwrap();
+    assert_ne!(hash, updated_hash);
+    assert_eq!(store.len(), 2);
+    assert_eq!(store.get(&hash).unwrap(), document);
+    assert_eq!(store.get(&updated_hash).unwrap(), updated);
+
+    assert!(store.get(&ContentHash::from([0; SHA256_LEN])).is_none());
+    assert!(!store.contains(&ContentHash::from([0; SHA256_LEN])));
+  }
+
+  #[test]
+  fn test_content_hash_display() {
+    let hash: ContentHash = ContentHash::from([0xab; SHA256_LEN]);
+    assert_eq!(hash.to_string(), format!("0x{}", "ab".repeat(SHA256_LEN)));
+  }
+}
@@ -10,6 +10,7 @@ use identity_did::DIDUrl;
 use identity_document::verifiable::JwsVerificationOptions;
 use identity_verification::jose::jws::DecodedJws;
 use identity_verification::jose::jws::JwsVerifier;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -17,8 +18,12 @@ use identity_core::common::Object;
 use identity_core::common::OneOrSet;
 use identity_core::common::OrderedSet;
 use identity_core::common::Url;
+use identity_core::common::Value;
 use identity_core::convert::FmtJson;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
 use identity_document::document::CoreDocument;
+use identity_document::document::DocumentChangeSet;
 use identity_document::service::Service;
 use identity_document::utils::DIDUrlQuery;
 use identity_verification::MethodRelationship;
@@ -177,6 +182,13 @@ impl IotaDocument {
     &mut self.document
   }
 
+  /// Consumes the document, returning the underlying [`CoreDocument`] and the [`IotaDocumentMetadata`].
+  ///
+  /// The inverse of the `TryFrom<(CoreDocument, IotaDocumentMetadata)>` conversion.
+  pub fn into_parts(self) -> (CoreDocument, IotaDocumentMetadata) {
+    (self.document, self.metadata)
+  }
+
   /// Returns a reference to the custom DID Document properties.
   pub fn properties(&self) -> &Object {
     self.document.properties()
@@ -193,6 +205,24 @@ impl IotaDocument {
     self.document.properties_mut_unchecked()
   }
 
+  /// Returns the custom DID Document property `key` deserialized as `T`.
+  ///
+  /// `None` is returned if the property does not exist or cannot be deserialized as `T`.
+  pub fn property_as<T>(&self, key: &str) -> Option<T>
+  where
+    T: DeserializeOwned,
+  {
+    self
+      .properties()
+      .get(key)
+      .and_then(|value| T::from_json_value(value.clone()).ok())
+  }
+
+  /// Removes and returns the custom DID Document property `key`, if it exists.
+  pub fn remove_property(&mut self, key: &str) -> Option<Value> {
+    self.document.properties_mut_unchecked().remove(key)
+  }
+
   // ===========================================================================
   // Services
   // ===========================================================================
@@ -221,6 +251,17 @@ impl IotaDocument {
     self.core_document_mut().remove_service(did_url)
   }
 
+  /// Removes the [`Service`]s identified by the given [`DIDUrl`]s from the document.
+  ///
+  /// Returns the number of services that were removed. Identifiers of services that do not exist in the document
+  /// are ignored.
+  pub fn remove_services<'a>(&mut self, did_urls: impl IntoIterator<Item = &'a DIDUrl>) -> usize {
+    did_urls
+      .into_iter()
+      .filter(|did_url| self.remove_service(did_url).is_some())
+      .count()
+  }
+
   // ===========================================================================
   // Verification Methods
   // ===========================================================================
@@ -254,6 +295,21 @@ impl IotaDocument {
     self.core_document_mut().remove_method(did_url)
   }
 
+  /// Removes the [`VerificationMethod`]s identified by the given [`DIDUrl`]s from the document.
+  ///
+  /// Returns the number of methods that were removed. Identifiers of methods that do not exist in the document are
+  /// ignored.
+  ///
+  /// # Note
+  ///
+  /// All _references to the removed methods_ found in the document will be removed, see [`Self::remove_method`].
+  pub fn remove_methods<'a>(&mut self, did_urls: impl IntoIterator<Item = &'a DIDUrl>) -> usize {
+    did_urls
+      .into_iter()
+      .filter(|did_url| self.remove_method(did_url).is_some())
+      .count()
+  }
+
   /// Removes and returns the [`VerificationMethod`] from the document. The [`MethodScope`] under which the method was
   /// found is appended to the second position of the returned tuple.
   ///
@@ -354,6 +410,65 @@ impl IotaDocument {
     self.document.resolve_method(method_query, scope)
   }
 
+  // ===========================================================================
+  // Merging
+  // ===========================================================================
+
+  /// Adds the verification methods, verification relationships and services of `other` that are missing from this
+  /// document. The metadata of this document is left as is.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if `other` has a different `id`, or if a method or service of `other` shares its identifier with
+  /// a different method or service in this document. This document is left unchanged on error.
+  pub fn merge_nonconflicting(&mut self, other: &IotaDocument) -> Result<()> {
+    self
+      .core_document_mut()
+      .merge_nonconflicting(other.core_document())
+      .map_err(Error::InvalidDoc)
+  }
+
+  /// Summarizes which verification methods and services were added, removed or changed in this document compared to
+  /// `previous`. See [`CoreDocument::changes_since`].
+  pub fn changes_since(&self, previous: &IotaDocument) -> DocumentChangeSet {
+    self.document.changes_since(previous.core_document())
+  }
+
+  /// Checks whether this document can be a later version of `prior`, e.g. a document that was resolved earlier and
+  /// pinned locally.
+  ///
+  /// This is the case if both documents have the same `id` and `created` timestamp, this document was not
+  /// `updated` before `prior`, and it keeps at least one of the `capabilityInvocation` methods of `prior` with the
+  /// same `id` and key material. A missing timestamp only matches another missing timestamp, and the last condition
+  /// does not apply if `prior` has no `capabilityInvocation` methods.
+  ///
+  /// # Note
+  ///
+  /// Whether an update was authorized is enforced by the ledger, through the state controller of the Alias Output,
+  /// and cannot be determined from the documents alone. This check only detects documents that cannot have been
+  /// produced by updating `prior`.
+  pub fn is_consistent_successor_of(&self, prior: &IotaDocument) -> bool {
+    let updated_in_order: bool = match (prior.metadata.updated, self.metadata.updated) {
+      (Some(prior_updated), Some(updated)) => prior_updated <= updated,
+      (None, _) => true,
+      (Some(_), None) => false,
+    };
+
+    let prior_invocation: Vec<&VerificationMethod> = prior.methods(Some(MethodScope::capability_invocation()));
+    let invocation: Vec<&VerificationMethod> = self.methods(Some(MethodScope::capability_invocation()));
+    let invocation_continued: bool = prior_invocation.is_empty()
+      || prior_invocation.iter().any(|prior_method| {
+        invocation
+          .iter()
+          .any(|method| method.id() == prior_method.id() && method.data() == prior_method.data())
+      });
+
+    self.id() == prior.id()
+      && self.metadata.created == prior.metadata.created
+      && updated_in_order
+      && invocation_continued
+  }
+
   // ===========================================================================
   // Signatures
   // ===========================================================================
@@ -378,6 +493,21 @@ impl IotaDocument {
       .map_err(Error::JwsVerificationError)
   }
 
+  // ===========================================================================
+  // Canonicalization
+  // ===========================================================================
+
+  /// Serializes the document, including its metadata, using the JSON Canonicalization Scheme (JCS).
+  ///
+  /// Documents that are semantically equal produce identical bytes regardless of the key order or
+  /// whitespace of the JSON they were parsed from, which makes the output suitable for computing
+  /// stable content hashes.
+  pub fn canonicalize(&self) -> Result<Vec<u8>> {
+    self
+      .to_jcs()
+      .map_err(|err| Error::SerializationError("failed to canonicalize the document", Some(err)))
+  }
+
   // ===========================================================================
   // Packing
   // ===========================================================================
@@ -720,6 +850,244 @@ mod tests {
     assert_eq!(0, document.service().len());
   }
 
+  #[test]
+  fn test_remove_methods_and_services() {
+    let controller: IotaDID = valid_did();
+    let mut document: IotaDocument = generate_document(&controller);
+    let urls: Vec<DIDUrl> = ["#key-1", "#key-3", "#auth-key", "#missing"]
+      .into_iter()
+      .map(|fragment| controller.to_url().join(fragment).unwrap())
+      .collect();
+
+    // The reference to `#key-3` is removed along with the method.
+    assert_eq!(document.remove_methods(&urls), 3);
+    let remaining: Vec<&str> = document
+      .methods(None)
+      .into_iter()
+      .map(|method| method.id().fragment().unwrap())
+      .collect();
+    assert_eq!(remaining, ["key-2"]);
+    assert!(document.core_document().authentication().is_empty());
+    assert_eq!(document.remove_methods(&urls), 0);
+
+    for fragment in ["#linked-domain", "#revocation"] {
+      let service: Service = Service::from_json(&format!(
+        r#"{{
+        "id":"{}",
+        "type": "LinkedDomains",
+        "serviceEndpoint": "https://bar.example.com"
+      }}"#,
+        controller.to_url().join(fragment).unwrap()
+      ))
+      .unwrap();
+      document.insert_service(service).unwrap();
+    }
+    let urls: Vec<DIDUrl> = ["#linked-domain", "#missing", "#revocation"]
+      .into_iter()
+      .map(|fragment| controller.to_url().join(fragment).unwrap())
+      .collect();
+    assert_eq!(document.remove_services(&urls), 2);
+    assert!(document.service().is_empty());
+    assert_eq!(document.remove_services(&urls), 0);
+  }
+
+  #[test]
+  fn test_merge_nonconflicting() {
+    let controller: IotaDID = valid_did();
+    let mut document: IotaDocument = generate_document(&controller);
+
+    let mut other: IotaDocument = document.clone();
+    other
+      .insert_method(generate_method(&controller, "#key-4"), MethodScope::VerificationMethod)
+      .unwrap();
+    document
+      .insert_method(generate_method(&controller, "#key-5"), MethodScope::VerificationMethod)
+      .unwrap();
+
+    document.merge_nonconflicting(&other).unwrap();
+    assert!(document.resolve_method("#key-4", None).is_some());
+    assert!(document.resolve_method("#key-5", None).is_some());
+
+    // The same fragment with different key material is a conflict.
+    let mut conflicting: IotaDocument = generate_document(&controller);
+    conflicting.remove_method(&controller.to_url().join("#key-1").unwrap());
+    conflicting
+      .insert_method(generate_method(&controller, "#key-1"), MethodScope::VerificationMethod)
+      .unwrap();
+    let expected: IotaDocument = document.clone();
+    assert!(matches!(
+      document.merge_nonconflicting(&conflicting).unwrap_err(),
+      Error::InvalidDoc(identity_document::Error::MergeConflict(_))
+    ));
+    assert_eq!(document, expected);
+  }
+
+  #[test]
+  fn test_method_with_different_controller() {
+    let did: IotaDID = valid_did();
+    let controller: IotaDID = "did:iota:0xBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBB"
+      .parse()
+      .unwrap();
+    let mut document: IotaDocument = generate_document(&did);
+
+    // A method embedded in this document may be controlled by another DID.
+    let mut method: VerificationMethod = generate_method(&did, "#delegated-key");
+    *method.controller_mut() = controller.clone().into();
+    document
+      .insert_method(method.clone(), MethodScope::VerificationMethod)
+      .unwrap();
+
+    let resolved: &VerificationMethod = document.resolve_method("#delegated-key", None).unwrap();
+    assert_eq!(resolved.controller(), controller.as_ref());
+    assert_eq!(resolved.id().did(), did.as_ref());
+
+    let deserialized: IotaDocument = IotaDocument::from_json(&document.to_json().unwrap()).unwrap();
+    assert_eq!(deserialized, document);
+  }
+
+  #[test]
+  fn test_is_consistent_successor_of() {
+    let controller: IotaDID = valid_did();
+    let prior: IotaDocument = generate_document(&controller);
+    assert!(prior.is_consistent_successor_of(&prior));
+
+    // A legitimate update.
+    let mut successor: IotaDocument = prior.clone();
+    successor
+      .insert_method(generate_method(&controller, "#key-4"), MethodScope::VerificationMethod)
+      .unwrap();
+    successor.metadata.updated = Some(Timestamp::parse("2020-01-03T00:00:00Z").unwrap());
+    assert!(successor.is_consistent_successor_of(&prior));
+    assert!(!prior.is_consistent_successor_of(&successor));
+
+    // A document for a different DID.
+    let other_did: IotaDID =
+      IotaDID::parse("did:iota:0x1111111111111111111111111111111111111111111111111111111111111111").unwrap();
+    let mut forged: IotaDocument = generate_document(&other_did);
+    forged.metadata.updated = successor.metadata.updated;
+    assert!(!forged.is_consistent_successor_of(&prior));
+
+    // A document with a rewritten creation time.
+    let mut forged: IotaDocument = successor.clone();
+    forged.metadata.created = Some(Timestamp::parse("2020-01-01T00:00:00Z").unwrap());
+    assert!(!forged.is_consistent_successor_of(&prior));
+
+    // A document without an update time.
+    let mut forged: IotaDocument = successor;
+    forged.metadata.updated = None;
+    assert!(!forged.is_consistent_successor_of(&prior));
+  }
+
+  #[test]
+  fn test_is_consistent_successor_of_capability_invocation() {
+    let controller: IotaDID = valid_did();
+    let mut prior: IotaDocument = generate_document(&controller);
+    prior
+      .insert_method(
+        generate_method(&controller, "#invoke-1"),
+        MethodScope::capability_invocation(),
+      )
+      .unwrap();
+    let updated: Option<Timestamp> = Some(Timestamp::parse("2020-01-03T00:00:00Z").unwrap());
+
+    // A key rotation that keeps one of the previous capability invocation methods.
+    let mut successor: IotaDocument = prior.clone();
+    successor
+      .insert_method(
+        generate_method(&controller, "#invoke-2"),
+        MethodScope::capability_invocation(),
+      )
+      .unwrap();
+    successor.metadata.updated = updated;
+    assert!(successor.is_consistent_successor_of(&prior));
+
+    // The capability invocation method keeps its id, but its key was swapped.
+    let mut forged: IotaDocument = prior.clone();
+    let invoke_1: DIDUrl = controller.to_url().join("#invoke-1").unwrap();
+    forged.remove_method(&invoke_1).unwrap();
+    forged
+      .insert_method(
+        generate_method(&controller, "#invoke-1"),
+        MethodScope::capability_invocation(),
+      )
+      .unwrap();
+    forged.metadata.updated = updated;
+    assert!(!forged.is_consistent_successor_of(&prior));
+
+    // The capability invocation method is demoted to a plain verification method.
+    let mut forged: IotaDocument = prior.clone();
+    let method: VerificationMethod = forged.remove_method(&invoke_1).unwrap();
+    forged.insert_method(method, MethodScope::VerificationMethod).unwrap();
+    forged.metadata.updated = updated;
+    assert!(!forged.is_consistent_successor_of(&prior));
+  }
+
+  #[test]
+  fn test_changes_since() {
+    let controller: IotaDID = valid_did();
+    let previous: IotaDocument = generate_document(&controller);
+    assert!(previous.changes_since(&previous).is_empty());
+
+    let mut document: IotaDocument = previous.clone();
+    // Add a service.
+    let service_url: DIDUrl = controller.to_url().join("#linked-domain").unwrap();
+    let service: Service = Service::from_json(&format!(
+      r#"{{
+      "id":"{service_url}",
+      "type": "LinkedDomains",
+      "serviceEndpoint": "https://bar.example.com"
+    }}"#
+    ))
+    .unwrap();
+    document.insert_service(service).unwrap();
+    // Remove a method.
+    let removed_url: DIDUrl = controller.to_url().join("#auth-key").unwrap();
+    document.remove_method(&removed_url).unwrap();
+    // Rotate a key.
+    let rotated_url: DIDUrl = controller.to_url().join("#key-1").unwrap();
+    document.remove_method(&rotated_url).unwrap();
+    document
+      .insert_method(generate_method(&controller, "#key-1"), MethodScope::VerificationMethod)
+      .unwrap();
+
+    let changes: DocumentChangeSet = document.changes_since(&previous);
+    assert_eq!(changes.added_services, vec![service_url]);
+    assert_eq!(changes.removed_methods, vec![removed_url]);
+    assert_eq!(changes.changed_methods, vec![rotated_url]);
+    assert!(changes.added_methods.is_empty());
+    assert!(changes.removed_services.is_empty());
+    assert!(changes.changed_services.is_empty());
+  }
+
+  #[test]
+  fn test_properties() {
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Metadata {
+      name: String,
+      version: u32,
+    }
+
+    let mut document: IotaDocument = generate_document(&valid_did());
+    let metadata: Metadata = Metadata {
+      name: "example".to_owned(),
+      version: 2,
+    };
+    document
+      .properties_mut_unchecked()
+      .insert("metadata".to_owned(), metadata.to_json_value().unwrap());
+
+    assert_eq!(document.property_as::<Metadata>("metadata"), Some(metadata));
+    // Mismatched type.
+    assert_eq!(document.property_as::<u32>("metadata"), None);
+    // Missing property.
+    assert_eq!(document.property_as::<Metadata>("missing"), None);
+
+    assert!(document.remove_property("metadata").is_some());
+    assert!(document.properties().is_empty());
+    assert!(document.remove_property("metadata").is_none());
+    assert_eq!(document.property_as::<Metadata>("metadata"), None);
+  }
+
   #[test]
   fn test_document_equality() {
     let mut original_doc: IotaDocument = IotaDocument::new_with_id(valid_did());
@@ -896,6 +1264,62 @@ mod tests {
     assert_eq!(document, de);
   }
 
+  #[test]
+  fn test_canonicalize() {
+    let did: IotaDID = valid_did();
+    let mut doc1: IotaDocument = IotaDocument::new_with_id(did.clone());
+    doc1.metadata.created = Some(Timestamp::parse("2020-01-02T00:00:00Z").unwrap());
+    doc1.metadata.updated = Some(Timestamp::parse("2020-01-02T00:00:00Z").unwrap());
+    doc1.properties_mut_unchecked().insert("foo".into(), 1.into());
+    doc1.properties_mut_unchecked().insert("bar".into(), "baz".into());
+
+    // Same content, but with a different key order and whitespace.
+    let json: String = format!(
+      r#"{{
+        "meta": {{ "updated": "2020-01-02T00:00:00Z", "created": "2020-01-02T00:00:00Z" }},
+        "doc": {{ "foo": 1, "bar": "baz", "id": "{did}" }}
+      }}"#
+    );
+    let doc2: IotaDocument = IotaDocument::from_json(&json).unwrap();
+    assert_eq!(doc1, doc2);
+    assert_eq!(doc1.canonicalize().unwrap(), doc2.canonicalize().unwrap());
+
+    let expected: String = format!(
+      r#"{{"doc":{{"bar":"baz","foo":1,"id":"{did}"}},"meta":{{"created":"2020-01-02T00:00:00Z","updated":"2020-01-02T00:00:00Z"}}}}"#
+    );
+    assert_eq!(doc1.canonicalize().unwrap(), expected.into_bytes());
+  }
+
+  #[test]
+  fn test_canonicalize_insertion_order() {
+    let did: IotaDID = valid_did();
+    let created: Timestamp = Timestamp::parse("2020-01-02T00:00:00Z").unwrap();
+    let updated: Timestamp = Timestamp::parse("2020-01-03T00:00:00Z").unwrap();
+    let nested = |json: &str| -> Value { Value::from_json(json).unwrap() };
+
+    let mut doc1: IotaDocument = IotaDocument::new_with_id(did.clone());
+    doc1.metadata.created = Some(created);
+    doc1.metadata.updated = Some(updated);
+    let properties: &mut Object = doc1.properties_mut_unchecked();
+    properties.insert("zeta".into(), nested(r#"{"b":[1,{"y":2,"x":1}],"a":"c"}"#));
+    properties.insert("alpha".into(), true.into());
+
+    // Same content, but with the properties and metadata set in the opposite order.
+    let mut doc2: IotaDocument = IotaDocument::new_with_id(did.clone());
+    doc2.metadata.updated = Some(updated);
+    doc2.metadata.created = Some(created);
+    let properties: &mut Object = doc2.properties_mut_unchecked();
+    properties.insert("alpha".into(), true.into());
+    properties.insert("zeta".into(), nested(r#"{"a":"c","b":[1,{"x":1,"y":2}]}"#));
+
+    assert_eq!(doc1, doc2);
+    assert_eq!(doc1.canonicalize().unwrap(), doc2.canonicalize().unwrap());
+    let expected: String = format!(
+      r#"{{"doc":{{"alpha":true,"id":"{did}","zeta":{{"a":"c","b":[1,{{"x":1,"y":2}}]}}}},"meta":{{"created":"2020-01-02T00:00:00Z","updated":"2020-01-03T00:00:00Z"}}}}"#
+    );
+    assert_eq!(doc1.canonicalize().unwrap(), expected.into_bytes());
+  }
+
   #[test]
   fn test_json_fieldnames() {
     // Changing the serialization is a breaking change!
@@ -1052,6 +1476,17 @@ mod tests {
     assert_eq!(&controllers, &[&expected_controller]);
   }
 
+  #[test]
+  fn into_parts_round_trip() {
+    let document: IotaDocument = generate_document(&valid_did());
+    let (core_document, metadata): (CoreDocument, IotaDocumentMetadata) = document.clone().into_parts();
+    assert_eq!(&core_document, document.core_document());
+    assert_eq!(metadata, document.metadata);
+
+    let recombined: IotaDocument = IotaDocument::try_from((core_document, metadata)).unwrap();
+    assert_eq!(recombined, document);
+  }
+
   #[test]
   fn try_from_doc_metadata() {
     const DOC_JSON_NOT_IOTA_DOCUMENT_BECAUSE_OF_ID: &str = r#"
@@ -1,11 +1,16 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub use document_store::ContentHash;
+pub use document_store::DocumentStore;
 pub use iota_document::IotaDocument;
 pub use iota_document_metadata::IotaDocumentMetadata;
+pub use validation::ValidationIssue;
 
+mod document_store;
 mod iota_document;
 mod iota_document_metadata;
+mod validation;
 
 #[cfg(test)]
 pub(crate) mod test_utils;
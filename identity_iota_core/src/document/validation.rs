@@ -0,0 +1,358 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_document::service::Service;
+use identity_verification::MethodData;
+use identity_verification::VerificationMethod;
+
+use crate::IotaDID;
+use crate::IotaDocument;
+
+/// The verification relationship properties of a DID document.
+const RELATIONSHIPS: [&str; 5] = [
+  "authentication",
+  "assertionMethod",
+  "keyAgreement",
+  "capabilityDelegation",
+  "capabilityInvocation",
+];
+
+/// A problem found while validating the JSON representation of a DID document with
+/// [`IotaDocument::validate_json`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ValidationIssue {
+  /// A JSON pointer to the offending value, e.g. `/verificationMethod/0/type`.
+  pub pointer: String,
+  /// A description of the problem.
+  pub message: &'static str,
+}
+
+impl ValidationIssue {
+  fn new(pointer: impl Into<String>, message: &'static str) -> Self {
+    Self {
+      pointer: pointer.into(),
+      message,
+    }
+  }
+}
+
+impl Display for ValidationIssue {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}: {}", self.pointer, self.message)
+  }
+}
+
+impl IotaDocument {
+  /// Checks whether `value` is the JSON representation of a DID document that can be used as the document of an
+  /// [`IotaDocument`], i.e. of the `doc` property of its serialization.
+  ///
+  /// Checks the presence and format of the `id`, `controller`, verification method and service properties, including
+  /// that the document and its controllers are valid [`IotaDID`]s.
+  ///
+  /// # Errors
+  ///
+  /// Returns all issues that were found, rather than only the first one.
+  pub fn validate_json(value: &Value) -> Result<(), Vec<ValidationIssue>> {
+    let mut issues: Vec<ValidationIssue> = Vec::new();
+
+    if !value.is_object() {
+      return Err(vec![ValidationIssue::new("", "expected a JSON object")]);
+    }
+
+    match value.get("id") {
+      None => issues.push(ValidationIssue::new("/id", "missing id")),
+      Some(id) if !is_iota_did(id) => issues.push(ValidationIssue::new(
+        "/id",
+        "id does not conform to the IOTA method specification",
+      )),
+      Some(_) => {}
+    }
+
+    match value.get("controller") {
+      None => {}
+      Some(Value::Array(controllers)) => {
+        for (index, controller) in controllers.iter().enumerate() {
+          if !is_iota_did(controller) {
+            issues.push(ValidationIssue::new(
+              format!("/controller/{index}"),
+              "controller does not conform to the IOTA method specification",
+            ));
+          }
+        }
+      }
+      Some(controller) if !is_iota_did(controller) => issues.push(ValidationIssue::new(
+        "/controller",
+        "controller does not conform to the IOTA method specification",
+      )),
+      Some(_) => {}
+    }
+
+    for (index, method) in array_entries(value, "verificationMethod", &mut issues) {
+      validate_method(&format!("/verificationMethod/{index}"), method, &mut issues);
+    }
+
+    for relationship in RELATIONSHIPS {
+      for (index, entry) in array_entries(value, relationship, &mut issues) {
+        let pointer: String = format!("/{relationship}/{index}");
+        match entry {
+          Value::String(reference) => {
+            if DIDUrl::parse(reference).is_err() {
+              issues.push(ValidationIssue::new(pointer, "invalid method reference"));
+            }
+          }
+          method => validate_method(&pointer, method, &mut issues),
+        }
+      }
+    }
+
+    for (index, service) in array_entries(value, "service", &mut issues) {
+      validate_service(&format!("/service/{index}"), service, &mut issues);
+    }
+
+    // Catch remaining problems, such as identifiers used more than once.
+    if issues.is_empty() && CoreDocument::from_json_value(value.clone()).is_err() {
+      issues.push(ValidationIssue::new("", "invalid DID document"));
+    }
+
+    if issues.is_empty() {
+      Ok(())
+    } else {
+      Err(issues)
+    }
+  }
+}
+
+fn is_iota_did(value: &Value) -> bool {
+  match value.as_str().map(CoreDID::parse) {
+    Some(Ok(did)) => IotaDID::check_validity(&did).is_ok(),
+    _ => false,
+  }
+}
+
+/// Returns the entries of the optional array property `key`, recording an issue if it is not an array.
+fn array_entries<'a>(
+  document: &'a Value,
+  key: &str,
+  issues: &mut Vec<ValidationIssue>,
+) -> impl Iterator<Item = (usize, &'a Value)> {
+  let entries: &[Value] = match document.get(key) {
+    None => &[],
+    Some(Value::Array(entries)) => entries,
+    Some(_) => {
+      issues.push(ValidationIssue::new(format!("/{key}"), "expected an array"));
+      &[]
+    }
+  };
+  entries.iter().enumerate()
+}
+
+/// Checks the common properties of the object at `pointer`, recording an issue for each problem found.
+///
+/// Returns `true` if no issues were found.
+fn validate_common(pointer: &str, value: &Value, issues: &mut Vec<ValidationIssue>) -> bool {
+  let Some(object) = value.as_object() else {
+    issues.push(ValidationIssue::new(pointer, "expected a JSON object"));
+    return false;
+  };
+  let issue_count: usize = issues.len();
+
+  match object.get("id").and_then(Value::as_str).map(DIDUrl::parse) {
+    None => issues.push(ValidationIssue::new(format!("{pointer}/id"), "missing id")),
+    Some(Ok(id)) if id.fragment().unwrap_or_default().is_empty() => issues.push(ValidationIssue::new(
+      format!("{pointer}/id"),
+      "id is missing a fragment",
+    )),
+    Some(Ok(_)) => {}
+    Some(Err(_)) => issues.push(ValidationIssue::new(format!("{pointer}/id"), "invalid DID URL")),
+  }
+
+  if !object.get("type").map(is_type).unwrap_or(false) {
+    issues.push(ValidationIssue::new(format!("{pointer}/type"), "missing type"));
+  }
+
+  issues.len() == issue_count
+}
+
+fn is_type(value: &Value) -> bool {
+  match value {
+    Value::String(_) => true,
+    Value::Array(types) => !types.is_empty() && types.iter().all(Value::is_string),
+    _ => false,
+  }
+}
+
+fn validate_method(pointer: &str, value: &Value, issues: &mut Vec<ValidationIssue>) {
+  let mut valid: bool = validate_common(pointer, value, issues);
+  if let Some(object) = value.as_object() {
+    match object.get("controller").and_then(Value::as_str).map(CoreDID::parse) {
+      None => {
+        issues.push(ValidationIssue::new(
+          format!("{pointer}/controller"),
+          "missing controller",
+        ));
+        valid = false;
+      }
+      Some(Err(_)) => {
+        issues.push(ValidationIssue::new(format!("{pointer}/controller"), "invalid DID"));
+        valid = false;
+      }
+      Some(Ok(_)) => {}
+    }
+  }
+
+  if !valid {
+    return;
+  }
+  match VerificationMethod::from_json_value(value.clone()) {
+    Err(_) => issues.push(ValidationIssue::new(pointer, "invalid verification method")),
+    Ok(method) => {
+      if matches!(method.data(), MethodData::PublicKeyJwk(jwk) if !jwk.is_public()) {
+        issues.push(ValidationIssue::new(pointer, "exposes private key material"));
+      } else if method.verify_key_data().is_err() {
+        issues.push(ValidationIssue::new(pointer, "invalid key material"));
+      }
+    }
+  }
+}
+
+fn validate_service(pointer: &str, value: &Value, issues: &mut Vec<ValidationIssue>) {
+  if validate_common(pointer, value, issues) && Service::from_json_value(value.clone()).is_err() {
+    issues.push(ValidationIssue::new(pointer, "invalid service"));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::ToJson;
+  use identity_verification::MethodRelationship;
+  use identity_verification::MethodScope;
+
+  use super::*;
+  use crate::test_utils::generate_method;
+
+  const DID: &str = "did:iota:0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+  fn pointers(issues: &[ValidationIssue]) -> Vec<&str> {
+    issues.iter().map(|issue| issue.pointer.as_str()).collect()
+  }
+
+  #[test]
+  fn test_valid_document() {
+    let did: IotaDID = IotaDID::parse(DID).unwrap();
+    let mut document: IotaDocument = IotaDocument::new_with_id(did.clone());
+    document
+      .insert_method(
+        generate_method(&did, "#key-1"),
+        MethodScope::VerificationRelationship(MethodRelationship::Authentication),
+      )
+      .unwrap();
+    let value: Value = document.core_document().to_json_value().unwrap();
+    assert_eq!(IotaDocument::validate_json(&value), Ok(()));
+  }
+
+  #[test]
+  fn test_not_an_object() {
+    let issues: Vec<ValidationIssue> = IotaDocument::validate_json(&Value::Null).unwrap_err();
+    assert_eq!(pointers(&issues), [""]);
+  }
+
+  #[test]
+  fn test_collects_all_issues() {
+    let value: Value = Value::from_json(&format!(
+      r#"{{
+      "id": "did:example:123",
+      "controller": ["{DID}", "did:foo:bar"],
+      "verificationMethod": [
+        {{
+          "id": "{DID}#key-1",
+          "type": "Ed25519VerificationKey2018",
+          "publicKeyMultibase": "zFVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z"
+        }},
+        {{
+          "id": "{DID}",
+          "controller": "{DID}",
+          "publicKeyMultibase": "zFVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z"
+        }}
+      ],
+      "authentication": ["not a DID URL"],
+      "service": {{
+        "id": "{DID}#linked-domain"
+      }}
+    }}"#
+    ))
+    .unwrap();
+
+    let issues: Vec<ValidationIssue> = IotaDocument::validate_json(&value).unwrap_err();
+    assert_eq!(
+      pointers(&issues),
+      [
+        "/id",
+        "/controller/1",
+        "/verificationMethod/0/controller",
+        "/verificationMethod/1/id",
+        "/verificationMethod/1/type",
+        "/authentication/0",
+        "/service",
+      ]
+    );
+    assert_eq!(
+      issues[0].message,
+      "id does not conform to the IOTA method specification"
+    );
+    assert_eq!(issues[3].message, "id is missing a fragment");
+  }
+
+  #[test]
+  fn test_invalid_key_material() {
+    let value: Value = Value::from_json(&format!(
+      r#"{{
+      "id": "{DID}",
+      "verificationMethod": [{{
+        "id": "{DID}#key-1",
+        "controller": "{DID}",
+        "type": "Ed25519VerificationKey2018",
+        "publicKeyMultibase": "zshort"
+      }}]
+    }}"#
+    ))
+    .unwrap();
+
+    let issues: Vec<ValidationIssue> = IotaDocument::validate_json(&value).unwrap_err();
+    assert_eq!(
+      issues,
+      [ValidationIssue::new("/verificationMethod/0", "invalid key material")]
+    );
+  }
+
+  #[test]
+  fn test_duplicate_ids() {
+    let method: String = format!(
+      r#"{{
+        "id": "{DID}#key-1",
+        "controller": "{DID}",
+        "type": "Ed25519VerificationKey2018",
+        "publicKeyMultibase": "zFVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z"
+      }}"#
+    );
+    let value: Value = Value::from_json(&format!(
+      r#"{{
+      "id": "{DID}",
+      "verificationMethod": [{method}],
+      "authentication": [{method}]
+    }}"#
+    ))
+    .unwrap();
+
+    let issues: Vec<ValidationIssue> = IotaDocument::validate_json(&value).unwrap_err();
+    assert_eq!(issues, [ValidationIssue::new("", "invalid DID document")]);
+  }
+}
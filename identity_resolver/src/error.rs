@@ -68,7 +68,29 @@ pub enum ErrorCause {
     /// The method that is unsupported.
     method: String,
   },
+  /// Caused by a failure to dereference a DID URL to a service endpoint.
+  #[error("service dereferencing failed: {message}")]
+  #[non_exhaustive]
+  ServiceDereferenceError {
+    /// A description of the failure.
+    message: &'static str,
+    /// The source of the error, if any.
+    #[source]
+    source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  },
   /// No client attached to the specific network.
   #[error("none of the attached clients support the network {0}")]
   UnsupportedNetwork(String),
+  /// Caused by a DID Document that is, directly or indirectly, controlled by itself.
+  #[error("controller resolution failed: the controller \"{did}\" forms a cycle")]
+  ControllerCycle {
+    /// The DID whose controller relationships form a cycle.
+    did: String,
+  },
+  /// Caused by a chain of controllers that is longer than the maximum allowed depth.
+  #[error("controller resolution failed: the controller chain exceeds the maximum depth of {max_depth}")]
+  ControllerDepthExceeded {
+    /// The maximum depth that was exceeded.
+    max_depth: usize,
+  },
 }
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod commands;
+#[cfg(feature = "iota")]
+mod resolution_output;
 mod resolver;
 #[cfg(test)]
 mod tests;
@@ -9,6 +11,14 @@ mod tests;
 use self::commands::SingleThreadedCommand;
 use identity_document::document::CoreDocument;
 
+#[cfg(feature = "iota")]
+pub use resolution_output::DocumentMetadata;
+#[cfg(feature = "iota")]
+pub use resolution_output::ResolutionErrorCode;
+#[cfg(feature = "iota")]
+pub use resolution_output::ResolutionMetadata;
+#[cfg(feature = "iota")]
+pub use resolution_output::ResolutionOutput;
 pub use resolver::Resolver;
 /// Alias for a [`Resolver`] that is not [`Send`] + [`Sync`].
 pub type SingleThreadedResolver<DOC = CoreDocument> = Resolver<DOC, SingleThreadedCommand<DOC>>;
@@ -0,0 +1,135 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use identity_core::common::Timestamp;
+use identity_iota_core::IotaDocument;
+use identity_iota_core::IotaDocumentMetadata;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Error;
+use crate::ErrorCause;
+
+/// The output of [`Resolver::resolve_with_metadata`](crate::Resolver::resolve_with_metadata), following the
+/// [DID resolution](https://www.w3.org/TR/did-core/#did-resolution) function of DID Core.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolutionOutput {
+  /// Metadata about the resolution process.
+  pub did_resolution_metadata: ResolutionMetadata,
+  /// The resolved document, or `None` if the resolution failed.
+  pub did_document: Option<IotaDocument>,
+  /// Metadata about the resolved document, empty if the resolution failed.
+  pub did_document_metadata: DocumentMetadata,
+}
+
+/// Metadata about the DID resolution process.
+///
+/// [Specification](https://www.w3.org/TR/did-core/#did-resolution-metadata)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolutionMetadata {
+  /// The media type of the representation of the resolved document.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub content_type: Option<String>,
+  /// The error code of a failed resolution.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<ResolutionErrorCode>,
+}
+
+impl ResolutionMetadata {
+  /// The media type of the JSON representation of a DID Document.
+  pub const DID_JSON_CONTENT_TYPE: &'static str = "application/did+json";
+}
+
+/// Metadata about a resolved DID Document, taken from its [`IotaDocumentMetadata`].
+///
+/// [Specification](https://www.w3.org/TR/did-core/#did-document-metadata)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DocumentMetadata {
+  /// The timestamp of document creation.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub created: Option<Timestamp>,
+  /// The timestamp of the last update to the document.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub updated: Option<Timestamp>,
+  /// Signals whether the document is deactivated.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub deactivated: Option<bool>,
+}
+
+impl From<&IotaDocumentMetadata> for DocumentMetadata {
+  fn from(metadata: &IotaDocumentMetadata) -> Self {
+    Self {
+      created: metadata.created,
+      updated: metadata.updated,
+      deactivated: metadata.deactivated,
+    }
+  }
+}
+
+/// The error codes of a failed DID resolution.
+///
+/// [Specification](https://www.w3.org/TR/did-spec-registries/#error)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ResolutionErrorCode {
+  /// The DID is not valid.
+  InvalidDid,
+  /// The DID Document was not found.
+  NotFound,
+  /// The DID method, or the network of an IOTA DID, is not supported by the resolver.
+  MethodNotSupported,
+  /// The resolution failed for another reason.
+  InternalError,
+}
+
+impl ResolutionErrorCode {
+  /// Returns the error code as specified.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::InvalidDid => "invalidDid",
+      Self::NotFound => "notFound",
+      Self::MethodNotSupported => "methodNotSupported",
+      Self::InternalError => "internalError",
+    }
+  }
+}
+
+impl Display for ResolutionErrorCode {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl From<&Error> for ResolutionErrorCode {
+  fn from(error: &Error) -> Self {
+    match error.error_cause() {
+      ErrorCause::DIDParsingError { .. } => Self::InvalidDid,
+      ErrorCause::UnsupportedMethodError { .. } | ErrorCause::UnsupportedNetwork(_) => Self::MethodNotSupported,
+      ErrorCause::HandlerError { .. } if is_not_found(error) => Self::NotFound,
+      _ => Self::InternalError,
+    }
+  }
+}
+
+/// Checks whether `error` was caused by a missing Alias Output, the only way an IOTA DID can fail to be found.
+fn is_not_found(error: &(dyn std::error::Error + 'static)) -> bool {
+  use iota_sdk::client::node_api::error::Error as NodeError;
+  use iota_sdk::client::Error as ClientError;
+
+  let mut source: Option<&(dyn std::error::Error + 'static)> = Some(error);
+  while let Some(error) = source {
+    if let Some(identity_iota_core::Error::DIDResolutionError(
+      ClientError::NoOutput(_) | ClientError::Node(NodeError::NotFound(_)),
+    )) = error.downcast_ref::<identity_iota_core::Error>()
+    {
+      return true;
+    }
+    source = error.source();
+  }
+  false
+}
@@ -2,12 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use core::future::Future;
+use futures::stream;
 use futures::stream::FuturesUnordered;
+use futures::StreamExt;
 use futures::TryStreamExt;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_did::DIDKey;
+use identity_did::DIDUrl;
 use identity_did::DID;
 use std::collections::HashSet;
+use std::convert::Infallible;
 
 use identity_document::document::CoreDocument;
+use identity_document::service::Service;
+use identity_document::service::ServiceEndpoint;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 
@@ -126,6 +137,187 @@ where
 
     Ok(documents)
   }
+
+  /// Concurrently fetches the DID Documents of the given DIDs, resolving at most `max_concurrency` of them at a time.
+  ///
+  /// Unlike [`Self::resolve_multiple`], a failed resolution does not abort the others: the outcome of each resolution
+  /// is returned in the same order as `dids`.
+  ///
+  /// ## Note
+  /// * A `max_concurrency` of zero is treated as one.
+  pub async fn resolve_each<D: DID>(&self, dids: &[D], max_concurrency: usize) -> Vec<Result<DOC>> {
+    stream::iter(dids)
+      .map(|did| self.resolve(did))
+      .buffered(max_concurrency.max(1))
+      .collect()
+      .await
+  }
+}
+
+impl<M, DOC> Resolver<DOC, M>
+where
+  M: for<'r> Command<'r, Result<DOC>>,
+  DOC: AsRef<CoreDocument>,
+{
+  /// Fetches the DID Document of the given DID and returns only its verification methods in the given `scope`.
+  ///
+  /// Returns all verification methods if `scope` is `None`. See [`CoreDocument::methods`].
+  ///
+  /// # Errors
+  ///
+  /// Errors if the DID cannot be resolved.
+  pub async fn resolve_methods<D: DID>(&self, did: &D, scope: Option<MethodScope>) -> Result<Vec<VerificationMethod>> {
+    let document: DOC = self.resolve(did).await?;
+    Ok(document.as_ref().methods(scope).into_iter().cloned().collect())
+  }
+
+  /// Fetches the DID Document of the given DID and, transitively, the DID Documents of its controllers.
+  ///
+  /// The documents are returned in breadth-first order, starting with the document of `did`. The document of a
+  /// controller reachable through multiple paths is only returned once, and a document listing itself as its
+  /// controller is ignored.
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the DIDs cannot be resolved, if the controller relationships form a cycle or if the chain of
+  /// controllers is longer than `max_depth`, where a `max_depth` of zero only allows uncontrolled documents.
+  pub async fn resolve_controller_chain<D: DID>(&self, did: &D, max_depth: usize) -> Result<Vec<DOC>> {
+    let did: CoreDID = did.clone().into();
+    let mut documents: Vec<DOC> = Vec::new();
+    // The controllers of every resolved DID.
+    let mut controllers: HashMap<CoreDID, Vec<CoreDID>> = HashMap::new();
+    // Every DID reachable so far, so that each is only resolved once.
+    let mut visited: HashSet<CoreDID> = HashSet::from([did.clone()]);
+    let mut level: Vec<CoreDID> = vec![did.clone()];
+
+    for depth in 0.. {
+      if level.is_empty() {
+        break;
+      }
+      if depth > max_depth {
+        return Err(Error::new(ErrorCause::ControllerDepthExceeded { max_depth }));
+      }
+
+      let mut next_level: Vec<CoreDID> = Vec::new();
+      for did in level {
+        let document: DOC = self.resolve(&did).await?;
+        let document_controllers: Vec<CoreDID> = document
+          .as_ref()
+          .controller()
+          .into_iter()
+          .flat_map(|controllers| controllers.iter())
+          .filter(|controller| *controller != &did)
+          .cloned()
+          .collect();
+        next_level.extend(
+          document_controllers
+            .iter()
+            .filter(|controller| visited.insert((*controller).clone()))
+            .cloned(),
+        );
+        controllers.insert(did, document_controllers);
+        documents.push(document);
+      }
+      level = next_level;
+    }
+
+    // A DID reachable through paths of different lengths was only visited along the shortest one, so check the
+    // longest chain and cycles on the complete controller graph.
+    controller_chain_length(&did, &controllers, 0, max_depth, &mut Vec::new(), &mut HashMap::new())?;
+
+    Ok(documents)
+  }
+
+  /// Dereferences a DID URL with a `service` query parameter to the endpoint of that service.
+  ///
+  /// The DID is resolved and the service whose fragment matches the `service` parameter is selected. If a
+  /// `relativeRef` parameter is present, it is resolved against the service endpoint as described in
+  /// [DID Core](https://www.w3.org/TR/did-core/#example-a-resource-external-to-a-did-document).
+  ///
+  /// E.g. `did:example:123?service=files&relativeRef=/resume.pdf` dereferences to
+  /// `https://example.com/resume.pdf` if the `#files` service has the endpoint `https://example.com/`.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the DID cannot be resolved, if the `service` parameter is missing or does not match a service in the
+  /// resolved document, or if the service endpoint is not a single URL.
+  pub async fn dereference_service(&self, did_url: &DIDUrl) -> Result<Url> {
+    let service_dereference_error =
+      |message: &'static str| Error::new(ErrorCause::ServiceDereferenceError { message, source: None });
+
+    let mut service_name: Option<String> = None;
+    let mut relative_ref: Option<String> = None;
+    for (key, value) in did_url.query_pairs() {
+      match key.as_ref() {
+        "service" => service_name = Some(value.into_owned()),
+        "relativeRef" => relative_ref = Some(value.into_owned()),
+        _ => {}
+      }
+    }
+    let service_name: String = service_name.ok_or_else(|| service_dereference_error("missing service parameter"))?;
+
+    let document: DOC = self.resolve(did_url.did()).await?;
+    let service: &Service = document
+      .as_ref()
+      .resolve_service(service_name.as_str())
+      .ok_or_else(|| service_dereference_error("service not found"))?;
+    let endpoint: &Url = match service.service_endpoint() {
+      ServiceEndpoint::One(endpoint) => endpoint,
+      ServiceEndpoint::Set(_) | ServiceEndpoint::Map(_) => {
+        return Err(service_dereference_error("service endpoint is not a single URL"));
+      }
+    };
+
+    match relative_ref {
+      None => Ok(endpoint.clone()),
+      Some(relative_ref) => endpoint.join(relative_ref).map_err(|err| {
+        Error::new(ErrorCause::ServiceDereferenceError {
+          message: "invalid relativeRef parameter",
+          source: Some(err.into()),
+        })
+      }),
+    }
+  }
+}
+
+/// Returns the length of the longest chain of controllers starting at `did`, which is reached at `depth`.
+///
+/// Errors if the chain contains a cycle or if it reaches beyond `max_depth`. `path` holds the DIDs leading to `did`
+/// and `lengths` memoizes the result for every DID whose chains were checked.
+fn controller_chain_length(
+  did: &CoreDID,
+  controllers: &HashMap<CoreDID, Vec<CoreDID>>,
+  depth: usize,
+  max_depth: usize,
+  path: &mut Vec<CoreDID>,
+  lengths: &mut HashMap<CoreDID, usize>,
+) -> Result<usize> {
+  if depth > max_depth {
+    return Err(Error::new(ErrorCause::ControllerDepthExceeded { max_depth }));
+  }
+  let length: usize = match lengths.get(did) {
+    Some(length) => *length,
+    None => {
+      path.push(did.clone());
+      let mut length: usize = 0;
+      for controller in &controllers[did] {
+        if path.contains(controller) {
+          return Err(Error::new(ErrorCause::ControllerCycle {
+            did: controller.to_string(),
+          }));
+        }
+        length = length.max(1 + controller_chain_length(controller, controllers, depth + 1, max_depth, path, lengths)?);
+      }
+      path.pop();
+      lengths.insert(did.clone(), length);
+      length
+    }
+  };
+  if depth + length > max_depth {
+    return Err(Error::new(ErrorCause::ControllerDepthExceeded { max_depth }));
+  }
+
+  Ok(length)
 }
 
 impl<DOC: 'static> Resolver<DOC, SendSyncCommand<DOC>> {
@@ -247,11 +439,35 @@ impl<DOC: 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
   }
 }
 
+impl<DOC> Resolver<DOC>
+where
+  DOC: From<CoreDocument> + AsRef<CoreDocument> + 'static,
+{
+  /// Convenience method for attaching a handler responsible for resolving `did:key` DIDs.
+  ///
+  /// The DID Document is derived from the public key encoded in the DID, see [`CoreDocument::from_did_key`],
+  /// so resolution never requires network access.
+  ///
+  /// See also [`attach_handler`](Self::attach_handler).
+  pub fn attach_did_key_handler(&mut self) {
+    let handler = |did: DIDKey| async move { Ok::<_, Infallible>(CoreDocument::from_did_key(&did)) };
+
+    self.attach_handler(DIDKey::METHOD.to_owned(), handler);
+  }
+}
+
 #[cfg(feature = "iota")]
 mod iota_handler {
+  use crate::DocumentMetadata;
   use crate::ErrorCause;
+  use crate::ResolutionErrorCode;
+  use crate::ResolutionMetadata;
+  use crate::ResolutionOutput;
+  use crate::Result;
 
+  use super::Command;
   use super::Resolver;
+  use identity_did::DID;
   use identity_document::document::CoreDocument;
   use identity_iota_core::IotaDID;
   use identity_iota_core::IotaDocument;
@@ -259,6 +475,37 @@ mod iota_handler {
   use std::collections::HashMap;
   use std::sync::Arc;
 
+  impl<M> Resolver<IotaDocument, M>
+  where
+    M: for<'r> Command<'r, Result<IotaDocument>>,
+  {
+    /// Fetches the DID Document of the given DID together with the document and resolution metadata defined by
+    /// [DID Core](https://www.w3.org/TR/did-core/#did-resolution).
+    ///
+    /// Unlike [`resolve`](Self::resolve()), a failed resolution is reported through the `error` code of the
+    /// resolution metadata, e.g. [`ResolutionErrorCode::NotFound`] if no Alias Output exists for an IOTA DID.
+    pub async fn resolve_with_metadata<D: DID>(&self, did: &D) -> ResolutionOutput {
+      match self.resolve(did).await {
+        Ok(document) => ResolutionOutput {
+          did_resolution_metadata: ResolutionMetadata {
+            content_type: Some(ResolutionMetadata::DID_JSON_CONTENT_TYPE.to_owned()),
+            error: None,
+          },
+          did_document_metadata: DocumentMetadata::from(&document.metadata),
+          did_document: Some(document),
+        },
+        Err(error) => ResolutionOutput {
+          did_resolution_metadata: ResolutionMetadata {
+            content_type: None,
+            error: Some(ResolutionErrorCode::from(&error)),
+          },
+          did_document: None,
+          did_document_metadata: DocumentMetadata::default(),
+        },
+      }
+    }
+  }
+
   impl<DOC> Resolver<DOC>
   where
     DOC: From<IotaDocument> + AsRef<CoreDocument> + 'static,
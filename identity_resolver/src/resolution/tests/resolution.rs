@@ -5,13 +5,26 @@ use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::Debug;
 use std::str::FromStr;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 
+use identity_core::common::Object;
+use identity_core::common::Url;
 use identity_did::BaseDIDUrl;
 use identity_did::CoreDID;
+use identity_did::DIDKey;
+use identity_did::DIDUrl;
 use identity_did::Error as DIDError;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
 use identity_document::document::DocumentBuilder;
+use identity_document::service::Service;
+use identity_document::service::ServiceEndpoint;
+use identity_verification::MethodData;
+use identity_verification::MethodScope;
+use identity_verification::MethodType;
+use identity_verification::VerificationMethod;
 
 use crate::Error as ResolverError;
 use crate::ErrorCause;
@@ -279,3 +292,487 @@ async fn resolve_multiple() {
   assert_eq!(resolved_dids.len(), 1);
   assert_eq!(resolved_dids.get(&did_1).unwrap().id(), &did_1);
 }
+
+// ===========================================================================
+// Resolve Each.
+// ===========================================================================
+
+static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+static MAX_IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+/// A handler that records how many resolutions are in progress at once and fails for DIDs with a `fail` method id.
+async fn counting_handler(did: CoreDID) -> std::result::Result<CoreDocument, std::io::Error> {
+  let in_flight: usize = IN_FLIGHT.fetch_add(1, Ordering::SeqCst) + 1;
+  MAX_IN_FLIGHT.fetch_max(in_flight, Ordering::SeqCst);
+  for _ in 0..10 {
+    tokio::task::yield_now().await;
+  }
+  IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+
+  if did.method_id().starts_with("fail") {
+    Err(std::io::Error::new(std::io::ErrorKind::Other, "resolution failed"))
+  } else {
+    Ok(core_document(did))
+  }
+}
+
+#[tokio::test]
+async fn resolve_each() {
+  IN_FLIGHT.store(0, Ordering::SeqCst);
+  MAX_IN_FLIGHT.store(0, Ordering::SeqCst);
+  let method_name: String = "foo".to_owned();
+  let dids: Vec<CoreDID> = ["1111", "2222", "fail", "3333", "1111", "4444"]
+    .into_iter()
+    .map(|method_id| CoreDID::parse(format!("did:{method_name}:{method_id}")).unwrap())
+    .collect();
+
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler(method_name, counting_handler);
+
+  let results: Vec<crate::Result<CoreDocument>> = resolver.resolve_each(&dids, 2).await;
+  assert_eq!(results.len(), dids.len());
+  for (did, result) in dids.iter().zip(results) {
+    if did.method_id() == "fail" {
+      assert!(matches!(
+        result.unwrap_err().into_error_cause(),
+        ErrorCause::HandlerError { .. }
+      ));
+    } else {
+      assert_eq!(result.unwrap().id(), did);
+    }
+  }
+  // The resolutions run concurrently, but never more than two at a time.
+  assert_eq!(MAX_IN_FLIGHT.load(Ordering::SeqCst), 2);
+
+  let no_dids: &[CoreDID] = &[];
+  assert!(resolver.resolve_each(no_dids, 2).await.is_empty());
+}
+
+// ===========================================================================
+// Service dereferencing tests
+// ===========================================================================
+#[tokio::test]
+async fn dereference_service() {
+  async fn handler(did: CoreDID) -> std::result::Result<CoreDocument, std::io::Error> {
+    let service = |fragment: &str, endpoint: ServiceEndpoint| -> Service {
+      Service::builder(Object::new())
+        .id(did.clone().join(fragment).unwrap())
+        .type_("LinkedDomains")
+        .service_endpoint(endpoint)
+        .build()
+        .unwrap()
+    };
+
+    Ok(
+      DocumentBuilder::default()
+        .id(did.clone())
+        .service(service(
+          "#linked-domain",
+          Url::parse("https://example.com/files/").unwrap().into(),
+        ))
+        .service(service(
+          "#linked-domains",
+          ServiceEndpoint::set([
+            Url::parse("https://foo.example.com").unwrap(),
+            Url::parse("https://bar.example.com").unwrap(),
+          ])
+          .unwrap(),
+        ))
+        .build()
+        .unwrap(),
+    )
+  }
+
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), handler);
+
+  let dereference = |did_url: &str| {
+    let did_url: DIDUrl = DIDUrl::parse(did_url).unwrap();
+    let resolver = &resolver;
+    async move { resolver.dereference_service(&did_url).await }
+  };
+
+  assert_eq!(
+    dereference("did:foo:1234?service=linked-domain").await.unwrap(),
+    Url::parse("https://example.com/files/").unwrap()
+  );
+  assert_eq!(
+    dereference("did:foo:1234?service=linked-domain&relativeRef=resume.pdf")
+      .await
+      .unwrap(),
+    Url::parse("https://example.com/files/resume.pdf").unwrap()
+  );
+  assert_eq!(
+    dereference("did:foo:1234?service=linked-domain&relativeRef=/resume.pdf")
+      .await
+      .unwrap(),
+    Url::parse("https://example.com/resume.pdf").unwrap()
+  );
+
+  for did_url in [
+    // Unknown service name.
+    "did:foo:1234?service=unknown",
+    // Missing service parameter.
+    "did:foo:1234?relativeRef=resume.pdf",
+    // Endpoint is not a single URL.
+    "did:foo:1234?service=linked-domains",
+  ] {
+    assert!(matches!(
+      dereference(did_url).await.unwrap_err().into_error_cause(),
+      ErrorCause::ServiceDereferenceError { .. }
+    ));
+  }
+
+  // Resolution errors are propagated.
+  assert!(matches!(
+    dereference("did:bar:1234?service=linked-domain")
+      .await
+      .unwrap_err()
+      .into_error_cause(),
+    ErrorCause::UnsupportedMethodError { .. }
+  ));
+}
+
+// ===========================================================================
+// did:key.
+// ===========================================================================
+
+#[tokio::test]
+async fn resolve_did_key() {
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_did_key_handler();
+  resolver.attach_handler("foo".to_owned(), mock_handler);
+
+  let dids: Vec<CoreDID> = [
+    "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+    "did:foo:1234",
+    "did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F",
+  ]
+  .into_iter()
+  .map(|did| CoreDID::parse(did).unwrap())
+  .collect();
+  let resolved_dids: HashMap<CoreDID, CoreDocument> = resolver.resolve_multiple(&dids).await.unwrap();
+  assert_eq!(resolved_dids.len(), 3);
+  for did in &dids {
+    assert_eq!(resolved_dids.get(did).unwrap().id(), did);
+  }
+  let document: &CoreDocument = resolved_dids.get(&dids[0]).unwrap();
+  assert_eq!(
+    document,
+    &CoreDocument::from_did_key(&DIDKey::try_from(dids[0].clone()).unwrap())
+  );
+
+  // Keys with an unsupported multicodec cannot be resolved.
+  let secp256k1: CoreDID = CoreDID::parse("did:key:zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme").unwrap();
+  assert!(matches!(
+    resolver.resolve(&secp256k1).await.unwrap_err().into_error_cause(),
+    ErrorCause::DIDParsingError { .. }
+  ));
+}
+
+// ===========================================================================
+// Resolve Methods.
+// ===========================================================================
+
+fn method(did: &CoreDID, fragment: &str) -> VerificationMethod {
+  VerificationMethod::builder(Object::new())
+    .id(did.to_url().join(fragment).unwrap())
+    .controller(did.clone())
+    .type_(MethodType::ED25519_VERIFICATION_KEY_2018)
+    .data(MethodData::new_multibase([fragment.len() as u8; 32]))
+    .build()
+    .unwrap()
+}
+
+async fn methods_handler(did: CoreDID) -> std::result::Result<CoreDocument, std::io::Error> {
+  Ok(
+    CoreDocument::builder(Object::new())
+      .id(did.clone())
+      .verification_method(method(&did, "#key-1"))
+      .authentication(method(&did, "#auth-key"))
+      .authentication(did.to_url().join("#key-1").unwrap())
+      .key_agreement(method(&did, "#agreement-key"))
+      .build()
+      .unwrap(),
+  )
+}
+
+#[tokio::test]
+async fn resolve_methods() {
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), methods_handler);
+
+  let methods: Vec<VerificationMethod> = resolver
+    .resolve_methods(&did, Some(MethodScope::authentication()))
+    .await
+    .unwrap();
+  let fragments: Vec<&str> = methods.iter().map(|method| method.id().fragment().unwrap()).collect();
+  assert_eq!(fragments, ["auth-key", "key-1"]);
+
+  let methods: Vec<VerificationMethod> = resolver.resolve_methods(&did, None).await.unwrap();
+  assert_eq!(methods.len(), 3);
+
+  let unsupported: CoreDID = CoreDID::parse("did:bar:1234").unwrap();
+  assert!(matches!(
+    resolver
+      .resolve_methods(&unsupported, None)
+      .await
+      .unwrap_err()
+      .into_error_cause(),
+    ErrorCause::UnsupportedMethodError { .. }
+  ));
+}
+
+// ===========================================================================
+// Resolve Controller Chain.
+// ===========================================================================
+
+/// The method ids of `did:foo` DIDs and the method ids of their controllers.
+const CONTROLLERS: [(&str, &[&str]); 17] = [
+  ("a", &["b"]),
+  ("b", &["c", "b"]),
+  ("c", &[]),
+  ("d", &["b", "e"]),
+  ("e", &["c"]),
+  ("x", &["y"]),
+  ("y", &["x"]),
+  ("m", &["n", "o"]),
+  ("n", &["o"]),
+  ("o", &["n"]),
+  ("p0", &["p1a", "p1b"]),
+  ("p1a", &["p2"]),
+  ("p1b", &["p2"]),
+  ("p2", &["p3a", "p3b"]),
+  ("p3a", &["p4"]),
+  ("p3b", &["p4"]),
+  ("p4", &[]),
+];
+
+/// Resolves `did:foo:<id>` to a document with the controllers listed for `<id>` in [`CONTROLLERS`].
+async fn controller_handler(did: CoreDID) -> std::result::Result<CoreDocument, std::io::Error> {
+  let controllers: Vec<CoreDID> = CONTROLLERS
+    .iter()
+    .find(|(id, _)| *id == did.method_id())
+    .map(|(_, controllers)| *controllers)
+    .unwrap_or_default()
+    .iter()
+    .copied()
+    .map(foo_did)
+    .collect();
+
+  let mut builder: DocumentBuilder = CoreDocument::builder(Object::new()).id(did);
+  for controller in controllers {
+    builder = builder.controller(controller);
+  }
+  Ok(builder.build().unwrap())
+}
+
+fn foo_did(id: &str) -> CoreDID {
+  CoreDID::parse(format!("did:foo:{id}")).unwrap()
+}
+
+#[tokio::test]
+async fn resolve_controller_chain() {
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), controller_handler);
+
+  // Two levels of controllers, where `b` also lists itself as a controller.
+  let documents: Vec<CoreDocument> = resolver.resolve_controller_chain(&foo_did("a"), 2).await.unwrap();
+  let ids: Vec<&CoreDID> = documents.iter().map(CoreDocument::id).collect();
+  assert_eq!(ids, [&foo_did("a"), &foo_did("b"), &foo_did("c")]);
+
+  // `c` is reachable through both `b` and `e`, but only resolved once.
+  let documents: Vec<CoreDocument> = resolver.resolve_controller_chain(&foo_did("d"), 2).await.unwrap();
+  let ids: Vec<&CoreDID> = documents.iter().map(CoreDocument::id).collect();
+  assert_eq!(ids, [&foo_did("d"), &foo_did("b"), &foo_did("e"), &foo_did("c")]);
+
+  let documents: Vec<CoreDocument> = resolver.resolve_controller_chain(&foo_did("c"), 0).await.unwrap();
+  assert_eq!(documents.len(), 1);
+
+  assert!(matches!(
+    resolver
+      .resolve_controller_chain(&foo_did("a"), 1)
+      .await
+      .unwrap_err()
+      .into_error_cause(),
+    ErrorCause::ControllerDepthExceeded { max_depth: 1 }
+  ));
+}
+
+#[tokio::test]
+async fn resolve_controller_chain_cycle() {
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), controller_handler);
+
+  let error: ErrorCause = resolver
+    .resolve_controller_chain(&foo_did("x"), 10)
+    .await
+    .unwrap_err()
+    .into_error_cause();
+  assert!(matches!(error, ErrorCause::ControllerCycle { did } if did == "did:foo:x"));
+
+  // `n` and `o` control each other, but are both first reached from `m`.
+  let error: ErrorCause = resolver
+    .resolve_controller_chain(&foo_did("m"), 10)
+    .await
+    .unwrap_err()
+    .into_error_cause();
+  assert!(matches!(error, ErrorCause::ControllerCycle { did } if did == "did:foo:n"));
+}
+
+#[tokio::test]
+async fn resolve_controller_chain_diamond() {
+  let resolutions: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  let counter: Arc<AtomicUsize> = resolutions.clone();
+  resolver.attach_handler("foo".to_owned(), move |did: CoreDID| {
+    counter.fetch_add(1, Ordering::SeqCst);
+    controller_handler(did)
+  });
+
+  // Two stacked diamonds: `p2` and `p4` are each reachable through two paths, but only resolved once.
+  let documents: Vec<CoreDocument> = resolver.resolve_controller_chain(&foo_did("p0"), 4).await.unwrap();
+  let ids: Vec<&CoreDID> = documents.iter().map(CoreDocument::id).collect();
+  assert_eq!(
+    ids,
+    ["p0", "p1a", "p1b", "p2", "p3a", "p3b", "p4"]
+      .map(foo_did)
+      .iter()
+      .collect::<Vec<_>>()
+  );
+  assert_eq!(resolutions.load(Ordering::SeqCst), 7);
+
+  assert!(matches!(
+    resolver
+      .resolve_controller_chain(&foo_did("p0"), 3)
+      .await
+      .unwrap_err()
+      .into_error_cause(),
+    ErrorCause::ControllerDepthExceeded { max_depth: 3 }
+  ));
+}
+
+// ===========================================================================
+// Resolve With Metadata.
+// ===========================================================================
+
+#[cfg(feature = "iota")]
+mod resolve_with_metadata {
+  use identity_core::common::Timestamp;
+  use identity_core::convert::ToJson;
+  use identity_iota_core::IotaDID;
+  use identity_iota_core::IotaDocument;
+  use iota_sdk::client::node_api::error::Error as NodeError;
+  use iota_sdk::client::Error as ClientError;
+
+  use super::*;
+  use crate::DocumentMetadata;
+  use crate::ResolutionErrorCode;
+  use crate::ResolutionMetadata;
+  use crate::ResolutionOutput;
+
+  const PUBLISHED_DID: &str = "did:iota:0x1111111111111111111111111111111111111111111111111111111111111111";
+  const MISSING_DID: &str = "did:iota:0x2222222222222222222222222222222222222222222222222222222222222222";
+  const PRUNED_DID: &str = "did:iota:0x3333333333333333333333333333333333333333333333333333333333333333";
+
+  async fn handler(did: IotaDID) -> std::result::Result<IotaDocument, identity_iota_core::Error> {
+    match did.as_str() {
+      PUBLISHED_DID => {
+        let mut document: IotaDocument = IotaDocument::new_with_id(did);
+        document.metadata.created = Some(Timestamp::parse("2020-01-02T00:00:00Z").unwrap());
+        document.metadata.updated = Some(Timestamp::parse("2020-01-03T00:00:00Z").unwrap());
+        document.metadata.deactivated = Some(false);
+        Ok(document)
+      }
+      MISSING_DID => Err(identity_iota_core::Error::DIDResolutionError(ClientError::NoOutput(
+        did.to_string(),
+      ))),
+      PRUNED_DID => Err(identity_iota_core::Error::DIDResolutionError(ClientError::Node(
+        NodeError::NotFound(did.to_string()),
+      ))),
+      _ => Err(identity_iota_core::Error::DIDResolutionError(
+        ClientError::PlaceholderSecretManager,
+      )),
+    }
+  }
+
+  fn resolver() -> Resolver<IotaDocument> {
+    let mut resolver: Resolver<IotaDocument> = Resolver::new();
+    resolver.attach_handler(IotaDID::METHOD.to_owned(), handler);
+    resolver
+  }
+
+  async fn error_code(resolver: &Resolver<IotaDocument>, did: &str) -> Option<ResolutionErrorCode> {
+    let output: ResolutionOutput = resolver.resolve_with_metadata(&CoreDID::parse(did).unwrap()).await;
+    assert!(output.did_document.is_none());
+    assert_eq!(output.did_document_metadata, DocumentMetadata::default());
+    assert!(output.did_resolution_metadata.content_type.is_none());
+    output.did_resolution_metadata.error
+  }
+
+  #[tokio::test]
+  async fn resolvable_did() {
+    let did: IotaDID = IotaDID::parse(PUBLISHED_DID).unwrap();
+    let output: ResolutionOutput = resolver().resolve_with_metadata(&did).await;
+
+    assert_eq!(output.did_document.unwrap().id(), &did);
+    assert_eq!(
+      output.did_resolution_metadata,
+      ResolutionMetadata {
+        content_type: Some("application/did+json".to_owned()),
+        error: None,
+      }
+    );
+    assert_eq!(
+      output.did_document_metadata,
+      DocumentMetadata {
+        created: Some(Timestamp::parse("2020-01-02T00:00:00Z").unwrap()),
+        updated: Some(Timestamp::parse("2020-01-03T00:00:00Z").unwrap()),
+        deactivated: Some(false),
+      }
+    );
+  }
+
+  #[tokio::test]
+  async fn not_found_did() {
+    let resolver: Resolver<IotaDocument> = resolver();
+    assert_eq!(
+      error_code(&resolver, MISSING_DID).await,
+      Some(ResolutionErrorCode::NotFound)
+    );
+    assert_eq!(
+      error_code(&resolver, PRUNED_DID).await,
+      Some(ResolutionErrorCode::NotFound)
+    );
+
+    let metadata: String = ResolutionMetadata {
+      content_type: None,
+      error: Some(ResolutionErrorCode::NotFound),
+    }
+    .to_json()
+    .unwrap();
+    assert_eq!(metadata, r#"{"error":"notFound"}"#);
+  }
+
+  #[tokio::test]
+  async fn other_error_codes() {
+    let resolver: Resolver<IotaDocument> = resolver();
+    assert_eq!(
+      error_code(&resolver, "did:iota:0x1234").await,
+      Some(ResolutionErrorCode::InvalidDid)
+    );
+    assert_eq!(
+      error_code(&resolver, "did:foo:1234").await,
+      Some(ResolutionErrorCode::MethodNotSupported)
+    );
+    assert_eq!(
+      error_code(
+        &resolver,
+        "did:iota:0x4444444444444444444444444444444444444444444444444444444444444444"
+      )
+      .await,
+      Some(ResolutionErrorCode::InternalError)
+    );
+  }
+}
@@ -253,6 +253,16 @@ async fn create_jws_detached() {
     )
     .is_ok());
 
+  // Reattaching the signature to different data fails.
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      Some(b"other"),
+      &EdDSAJwsVerifier::default(),
+      &verification_options,
+    )
+    .is_err());
+
   // ==================
   // With B64 Encoding
   // ==================
@@ -273,6 +283,15 @@ async fn create_jws_detached() {
       &verification_options,
     )
     .is_ok());
+
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      Some(encode_b64(b"other").as_ref()),
+      &EdDSAJwsVerifier::default(),
+      &verification_options,
+    )
+    .is_err());
 }
 
 #[tokio::test]
@@ -19,6 +19,7 @@ use identity_credential::validator::JwtValidationError;
 use identity_did::CoreDID;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
 use identity_eddsa_verifier::EdDSAJwsVerifier;
 use identity_verification::jws::JwsAlgorithm;
 use identity_verification::MethodScope;
@@ -370,6 +371,100 @@ where
   assert!(validation_ok);
 }
 
+#[tokio::test]
+async fn challenge_and_audience() {
+  challenge_and_audience_impl(setup_coredocument(None, None).await).await;
+  challenge_and_audience_impl(setup_iotadocument(None, None).await).await;
+}
+
+async fn challenge_and_audience_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument> + Clone,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let jws = sign_credential(&setup, &credential.credential).await;
+
+  let presentation: Presentation<Jwt> =
+    PresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(jws)
+      .build()
+      .unwrap();
+
+  let challenge: &str = "475a7984-1bb5-4c4c-a56f-822bccd46440";
+  let audience: Url = Url::parse("https://verifier.example.com").unwrap();
+  let presentation_options = JwtPresentationOptions {
+    audience: Some(audience.clone()),
+    ..Default::default()
+  };
+
+  let presentation_jwt = setup
+    .subject_doc
+    .create_presentation_jwt(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default().nonce(challenge),
+      &presentation_options,
+    )
+    .await
+    .unwrap();
+
+  let validate = |presentation_jwt: &Jwt, options: &JwtPresentationValidationOptions| {
+    JWT_PRESENTATION_VALIDATOR_ED25519
+      .validate::<_, Jwt, Object>(presentation_jwt, &setup.subject_doc, options)
+      .map_err(|err| err.presentation_validation_errors.into_iter().next().unwrap())
+  };
+
+  // Matching challenge and audience.
+  let validation_options = JwtPresentationValidationOptions::default()
+    .presentation_verifier_options(JwsVerificationOptions::default().nonce(challenge))
+    .audience(audience.clone());
+  let decoded_presentation: DecodedJwtPresentation<Jwt> = validate(&presentation_jwt, &validation_options).unwrap();
+  assert_eq!(decoded_presentation.aud, Some(audience.clone()));
+
+  // Mismatched challenge.
+  let validation_options = JwtPresentationValidationOptions::default()
+    .presentation_verifier_options(JwsVerificationOptions::default().nonce("other-challenge"))
+    .audience(audience.clone());
+  assert!(matches!(
+    validate(&presentation_jwt, &validation_options).unwrap_err(),
+    JwtValidationError::PresentationJwsError(identity_document::Error::JwsVerificationError(_))
+  ));
+
+  // Mismatched audience.
+  let validation_options = JwtPresentationValidationOptions::default()
+    .presentation_verifier_options(JwsVerificationOptions::default().nonce(challenge))
+    .audience(Url::parse("https://other.example.com").unwrap());
+  assert!(matches!(
+    validate(&presentation_jwt, &validation_options).unwrap_err(),
+    JwtValidationError::AudienceMismatch
+  ));
+
+  // Missing challenge and audience.
+  let unbound_presentation_jwt = setup
+    .subject_doc
+    .create_presentation_jwt(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+  let validation_options = JwtPresentationValidationOptions::default()
+    .presentation_verifier_options(JwsVerificationOptions::default().nonce(challenge));
+  assert!(matches!(
+    validate(&unbound_presentation_jwt, &validation_options).unwrap_err(),
+    JwtValidationError::PresentationJwsError(identity_document::Error::JwsVerificationError(_))
+  ));
+  let validation_options = JwtPresentationValidationOptions::default().audience(audience);
+  assert!(matches!(
+    validate(&unbound_presentation_jwt, &validation_options).unwrap_err(),
+    JwtValidationError::AudienceMismatch
+  ));
+}
+
 #[tokio::test]
 async fn presentation_jws_error() {
   presentation_jws_error_impl(setup_coredocument(None, None).await).await;
@@ -21,13 +21,16 @@ use identity_verification::jws::JwsAlgorithm;
 use identity_verification::jwu;
 use iota_sdk::client::secret::stronghold::StrongholdSecretManager;
 use iota_sdk::client::secret::SecretManager;
+use iota_sdk::client::Password;
 #[cfg(feature = "bbs-plus")]
 use iota_stronghold::procedures::FatalProcedureError;
 use iota_stronghold::procedures::KeyType as ProceduresKeyType;
 #[cfg(feature = "bbs-plus")]
 use iota_stronghold::procedures::Runner as _;
 use iota_stronghold::procedures::StrongholdProcedure;
+use iota_stronghold::KeyProvider;
 use iota_stronghold::Location;
+use iota_stronghold::SnapshotPath;
 use iota_stronghold::Stronghold;
 #[cfg(feature = "bbs-plus")]
 use jsonprooftoken::jpa::algs::ProofAlgorithm;
@@ -39,6 +42,9 @@ use zkryptium::bbsplus::keys::BBSplusSecretKey;
 
 use crate::stronghold_key_type::StrongholdKeyType;
 use crate::utils::get_client;
+use crate::utils::persist_changes;
+use crate::utils::random_temp_snapshot_path;
+use crate::utils::IDENTITY_CLIENT_PATH;
 use crate::utils::IDENTITY_VAULT_PATH;
 
 /// Wrapper around a [`StrongholdSecretManager`] that implements the [`KeyIdStorage`](crate::KeyIdStorage)
@@ -129,6 +135,83 @@ impl StrongholdStorage {
     }
   }
 
+  /// Exports the entire Stronghold backing this storage as a snapshot encrypted with `password`.
+  ///
+  /// Besides the keys held by this storage, the snapshot contains the state of every other client of the
+  /// underlying Stronghold, e.g. the mnemonic or seed stored by the [`StrongholdSecretManager`], so it must be
+  /// protected accordingly.
+  ///
+  /// The returned bytes are independent of the snapshot path this storage was created with and can be
+  /// restored into any [`StrongholdStorage`] using [`StrongholdStorage::import_snapshot`].
+  pub async fn export_snapshot(&self, password: Password) -> KeyStorageResult<Vec<u8>> {
+    let key_provider: KeyProvider = key_provider_from_password(password)?;
+    let stronghold = self.get_stronghold().await;
+    // Ensures the identity client is loaded so that its latest state is part of the snapshot.
+    get_client(&stronghold)?;
+
+    let path = random_temp_snapshot_path();
+    let result = stronghold
+      .commit_with_keyprovider(&SnapshotPath::from_path(&path), &key_provider)
+      .map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("writing stronghold snapshot failed")
+          .with_source(err)
+      })
+      .and_then(|_| {
+        std::fs::read(&path).map_err(|err| {
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message("reading stronghold snapshot failed")
+            .with_source(err)
+        })
+      });
+    let _ = std::fs::remove_file(&path);
+
+    result
+  }
+
+  /// Replaces the keys held by this storage with those contained in `snapshot`, which must have been
+  /// produced by [`StrongholdStorage::export_snapshot`] using the same `password`.
+  ///
+  /// Like the export, the import covers the entire Stronghold, so the state of other clients, e.g. the mnemonic
+  /// or seed stored by the [`StrongholdSecretManager`], is replaced by the one contained in `snapshot` as well.
+  /// The result is persisted to the snapshot path of this storage, encrypted with its own password. If the
+  /// snapshot cannot be decrypted, the previous keys remain loaded.
+  pub async fn import_snapshot(&self, snapshot: &[u8], password: Password) -> KeyStorageResult<()> {
+    let key_provider: KeyProvider = key_provider_from_password(password)?;
+    let path = random_temp_snapshot_path();
+    std::fs::write(&path, snapshot).map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("staging stronghold snapshot failed")
+        .with_source(err)
+    })?;
+
+    let stronghold = self.get_stronghold().await;
+    let unloaded: bool = match stronghold.get_client(IDENTITY_CLIENT_PATH) {
+      Ok(client) => {
+        // Keep the current state in the in-memory snapshot so it can be reloaded should the import fail.
+        let _ = stronghold.write_client(IDENTITY_CLIENT_PATH);
+        stronghold.unload_client(client).is_ok()
+      }
+      Err(_) => false,
+    };
+
+    let result =
+      stronghold.load_client_from_snapshot(IDENTITY_CLIENT_PATH, &key_provider, &SnapshotPath::from_path(&path));
+    let _ = std::fs::remove_file(&path);
+    if let Err(err) = result {
+      if unloaded {
+        let _ = stronghold.load_client(IDENTITY_CLIENT_PATH);
+      }
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("loading stronghold snapshot failed")
+          .with_source(err),
+      );
+    }
+
+    persist_changes(self.as_secret_manager(), stronghold).await
+  }
+
   /// Retrieve the public key corresponding to `key_id`.
   #[deprecated(since = "1.3.0", note = "use `get_public_key_with_type` instead")]
   pub async fn get_public_key(&self, key_id: &KeyId) -> KeyStorageResult<Jwk> {
@@ -161,3 +244,12 @@ impl StrongholdStorage {
     Ok(jwk)
   }
 }
+
+/// Hashes `password` into a [`KeyProvider`] the same way [`StrongholdSecretManager`] does.
+fn key_provider_from_password(password: Password) -> KeyStorageResult<KeyProvider> {
+  KeyProvider::with_passphrase_hashed_blake2b(password.as_bytes().to_vec()).map_err(|err| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_custom_message("invalid snapshot password")
+      .with_source(err)
+  })
+}
@@ -4,4 +4,5 @@
 mod test_bbs_ext;
 mod test_jwk_storage;
 mod test_key_id_storage;
+mod test_snapshot;
 pub(crate) mod utils;
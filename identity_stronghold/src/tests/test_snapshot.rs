@@ -0,0 +1,90 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_storage::key_storage::JwkStorage;
+use identity_storage::key_storage::KeyType;
+use identity_verification::jws::JwsAlgorithm;
+use iota_sdk::client::Password;
+
+use super::utils::create_stronghold_secret_manager;
+use crate::utils::IDENTITY_CLIENT_PATH;
+use crate::StrongholdStorage;
+
+#[tokio::test]
+async fn export_and_import_snapshot() {
+  const PASS: &str = "backup_password";
+  let test_msg: &[u8] = b"test";
+  let stronghold_storage = StrongholdStorage::new(create_stronghold_secret_manager());
+
+  let generate = stronghold_storage
+    .generate(KeyType::new("Ed25519"), JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+  let signature = stronghold_storage
+    .sign(&generate.key_id, test_msg, &generate.jwk)
+    .await
+    .unwrap();
+
+  let snapshot: Vec<u8> = stronghold_storage
+    .export_snapshot(Password::from(PASS.to_owned()))
+    .await
+    .unwrap();
+
+  // INVALID: importing with the wrong password fails.
+  let fresh_storage = StrongholdStorage::new(create_stronghold_secret_manager());
+  assert!(fresh_storage
+    .import_snapshot(&snapshot, Password::from("wrong_password".to_owned()))
+    .await
+    .is_err());
+  assert!(!fresh_storage.exists(&generate.key_id).await.unwrap());
+
+  // VALID: the imported key signs identically under the same key id.
+  fresh_storage
+    .import_snapshot(&snapshot, Password::from(PASS.to_owned()))
+    .await
+    .unwrap();
+  assert!(fresh_storage.exists(&generate.key_id).await.unwrap());
+  let imported_signature = fresh_storage
+    .sign(&generate.key_id, test_msg, &generate.jwk)
+    .await
+    .unwrap();
+  assert_eq!(signature, imported_signature);
+}
+
+#[tokio::test]
+async fn failed_import_reloads_previous_keys() {
+  let test_msg: &[u8] = b"test";
+  let stronghold_storage = StrongholdStorage::new(create_stronghold_secret_manager());
+  let snapshot: Vec<u8> = stronghold_storage
+    .export_snapshot(Password::from("backup_password".to_owned()))
+    .await
+    .unwrap();
+
+  let target_storage = StrongholdStorage::new(create_stronghold_secret_manager());
+  let generate = target_storage
+    .generate(KeyType::new("Ed25519"), JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+  let signature = target_storage
+    .sign(&generate.key_id, test_msg, &generate.jwk)
+    .await
+    .unwrap();
+
+  assert!(target_storage
+    .import_snapshot(&snapshot, Password::from("wrong_password".to_owned()))
+    .await
+    .is_err());
+
+  // The previous client is loaded again right away instead of being left for lazy loading.
+  assert!(target_storage
+    .get_stronghold()
+    .await
+    .get_client(IDENTITY_CLIENT_PATH)
+    .is_ok());
+  assert!(target_storage.exists(&generate.key_id).await.unwrap());
+  let reloaded_signature = target_storage
+    .sign(&generate.key_id, test_msg, &generate.jwk)
+    .await
+    .unwrap();
+  assert_eq!(signature, reloaded_signature);
+}
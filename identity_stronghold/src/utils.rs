@@ -11,6 +11,7 @@ use iota_stronghold::Client;
 use iota_stronghold::ClientError;
 use iota_stronghold::Stronghold;
 use rand::distributions::DistString as _;
+use std::path::PathBuf;
 use tokio::sync::MutexGuard;
 
 use crate::stronghold_key_type::StrongholdKeyType;
@@ -23,6 +24,14 @@ pub fn random_key_id() -> KeyId {
   KeyId::new(rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 32))
 }
 
+/// Returns a random path in the system's temporary directory for staging snapshot files.
+pub fn random_temp_snapshot_path() -> PathBuf {
+  let mut file = std::env::temp_dir();
+  file.push(rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 32));
+  file.set_extension("stronghold");
+  file
+}
+
 /// Check that the key type can be used with the algorithm.
 pub fn check_key_alg_compatibility(key_type: StrongholdKeyType, alg: JwsAlgorithm) -> KeyStorageResult<()> {
   match (key_type, alg) {
@@ -29,6 +29,15 @@ pub enum Error {
   /// Caused by key material in a [`MethodData`](crate::MethodData) that is expected to be multibase encoded.
   #[error("invalid multibase key data")]
   InvalidKeyDataMultibase,
+  /// Caused by key material in a [`MethodData`](crate::MethodData) whose length does not match the one expected for
+  /// the [`MethodType`](crate::MethodType).
+  #[error("invalid key data length: expected {expected} bytes, found {actual}")]
+  InvalidKeyDataLength {
+    /// The key length expected for the method type.
+    expected: usize,
+    /// The length of the decoded key material.
+    actual: usize,
+  },
   /// Caused by attempting to decode [`MethodData`](crate::MethodData) that is not in the expected encoding.
   #[error("the method data could not be transformed to the desired type")]
   InvalidMethodDataTransformation(&'static str),
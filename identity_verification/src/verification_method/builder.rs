@@ -89,6 +89,59 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_verify_key_data() {
+    let method = |method_type: MethodType, data: MethodData| -> VerificationMethod {
+      MethodBuilder::default()
+        .id("did:example:123#key".parse().unwrap())
+        .controller("did:example:123".parse().unwrap())
+        .type_(method_type)
+        .data(data)
+        .build()
+        .unwrap()
+    };
+
+    for method_type in [
+      MethodType::ED25519_VERIFICATION_KEY_2018,
+      MethodType::X25519_KEY_AGREEMENT_KEY_2019,
+    ] {
+      for method_data_fn in [MethodData::new_base58, MethodData::new_multibase] {
+        assert!(method(method_type.clone(), method_data_fn([0; 32].as_slice()))
+          .verify_key_data()
+          .is_ok());
+
+        // A truncated key is rejected.
+        assert!(matches!(
+          method(method_type.clone(), method_data_fn([0; 31].as_slice()))
+            .verify_key_data()
+            .unwrap_err(),
+          Error::InvalidKeyDataLength {
+            expected: 32,
+            actual: 31
+          }
+        ));
+      }
+    }
+
+    // Undecodable key material is rejected.
+    assert!(matches!(
+      method(
+        MethodType::ED25519_VERIFICATION_KEY_2018,
+        MethodData::PublicKeyBase58("0OIl".into())
+      )
+      .verify_key_data()
+      .unwrap_err(),
+      Error::InvalidKeyDataBase58
+    ));
+
+    // The length of unknown method types is not checked.
+    assert!(
+      method(MethodType::custom("CustomKeyType"), MethodData::new_multibase([0; 31]))
+        .verify_key_data()
+        .is_ok()
+    );
+  }
+
   #[test]
   fn test_missing_id_fragment() {
     let result: Result<VerificationMethod> = MethodBuilder::default()
@@ -148,6 +148,37 @@ impl VerificationMethod {
     &mut self.properties
   }
 
+  /// Checks that base-encoded key material decodes to a key of the length expected for the method type.
+  ///
+  /// Only [`MethodType::ED25519_VERIFICATION_KEY_2018`] and [`MethodType::X25519_KEY_AGREEMENT_KEY_2019`] are
+  /// checked, both of which require 32-byte keys. Other method types, as well as [`MethodData::PublicKeyJwk`] and
+  /// [`MethodData::Custom`] data, are accepted as-is.
+  ///
+  /// This check is not performed when a method is built or deserialized, so it must be called explicitly before
+  /// relying on the length of the key material.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the data cannot be decoded or does not have the expected length.
+  pub fn verify_key_data(&self) -> Result<()> {
+    let expected: usize = match self.type_.as_str() {
+      t if t == MethodType::ED25519_VERIFICATION_KEY_2018.as_str() => 32,
+      t if t == MethodType::X25519_KEY_AGREEMENT_KEY_2019.as_str() => 32,
+      _ => return Ok(()),
+    };
+
+    match self.data {
+      MethodData::PublicKeyMultibase(_) | MethodData::PublicKeyBase58(_) => {
+        let actual: usize = self.data.try_decode()?.len();
+        if actual != expected {
+          return Err(Error::InvalidKeyDataLength { expected, actual });
+        }
+        Ok(())
+      }
+      _ => Ok(()),
+    }
+  }
+
   /// Creates a new [`MethodRef`] from `self`.
   pub fn into_method_ref(self) -> MethodRef {
     MethodRef::Embed(self)